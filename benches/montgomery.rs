@@ -0,0 +1,94 @@
+//! Benchmarks of the routines built on Montgomery multiplication.
+//!
+//! Run with `cargo bench --bench montgomery`. The table in the documentation of
+//! `math::montgomery` compares this benchmark at the commit before Montgomery multiplication was
+//! introduced (where only the public functions used here exist) with the current tree.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use math_optim::math::conv::ntt::{intt, ntt, ntt_conv};
+use math_optim::math::gf::GF;
+use math_optim::math::prime::{factorize, is_prime};
+
+const MOD: u32 = 998_244_353;
+
+/// A xorshift generator, so that every run uses the same inputs.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 7;
+        self.0 ^= self.0 >> 9;
+        self.0
+    }
+}
+
+/// Number of samples taken by [`measure`]; the fastest is reported to filter out noise.
+const SAMPLES: usize = 5;
+
+/// Runs `f` once to warm up, then returns the shortest total time of `rounds` calls of `f` over
+/// [`SAMPLES`] samples.
+fn measure(rounds: usize, mut f: impl FnMut()) -> Duration {
+    f();
+    (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..rounds {
+                f();
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, time: Duration) {
+    println!("{:<44} {:>8.1} ms", name, time.as_secs_f64() * 1e3);
+}
+
+fn main() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    let odd: Vec<u64> = (0..1_000_000).map(|_| rng.next() | 1).collect();
+    let time = measure(1, || {
+        for &n in &odd {
+            black_box(is_prime(black_box(n)));
+        }
+    });
+    report("is_prime, 10^6 random odd u64", time);
+
+    // Products of two primes just below 2^30, so that Pollard's rho does the work.
+    let mut prime_near_2_30 = || loop {
+        let p = ((1 << 30) - rng.next() % (1 << 20)) | 1;
+        if is_prime(p) {
+            break p;
+        }
+    };
+    let semiprimes: Vec<u64> = (0..100)
+        .map(|_| prime_near_2_30() * prime_near_2_30())
+        .collect();
+    let time = measure(1, || {
+        for &n in &semiprimes {
+            black_box(factorize(black_box(n)));
+        }
+    });
+    report("factorize, 100 semiprimes near 2^60", time);
+
+    let n = 1 << 19;
+    let a: Vec<GF<MOD>> = (0..n)
+        .map(|_| GF::new((rng.next() % MOD as u64) as u32))
+        .collect();
+    let mut data = a.clone();
+    let time = measure(20, || {
+        ntt(&mut data);
+        intt(&mut data);
+    });
+    report("ntt + intt, 2^19 terms, 20 rounds", time);
+
+    let (lhs, rhs) = a.split_at(n / 2);
+    let time = measure(20, || {
+        black_box(ntt_conv(black_box(lhs), black_box(rhs)));
+    });
+    report("ntt_conv, 2^18 x 2^18 terms, 20 rounds", time);
+}
@@ -15,6 +15,10 @@
 //!   `a * e = e * a = a`.
 //! - **inverse**: for every `a` in `G`, there exists an element `a^(-1)` in `G` such that
 //!   `a * a^(-1) = a^(-1) * a = e`.
+//!
+//! Every group is a monoid, so every [`Group`] is also a [`Monoid`] with the same operation.
+
+use super::monoid::Monoid;
 
 /// A trait representating a *group*.
 ///
@@ -56,3 +60,21 @@ pub trait Group {
         Self::op(a, &Self::inv(b))
     }
 }
+
+/// Every group is a monoid with the same operation and identity.
+///
+/// This lets a [`Group`] be used directly where a [`Monoid`] is required, e.g. by
+/// [`SegTree`](crate::ds::segtree::SegTree).
+impl<T: Group> Monoid for T {
+    type S = T::G;
+
+    #[inline]
+    fn identity() -> Self::S {
+        T::identity()
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        T::op(a, b)
+    }
+}
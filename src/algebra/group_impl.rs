@@ -0,0 +1,345 @@
+//! Standard implementations of groups.
+//!
+//! This module provides commonly used group implementations.
+//!
+//! # Available groups
+//!
+//! - [`AdditiveGroup`]: Addition with negation
+//! - [`XorGroup`]: Bitwise xor
+//! - [`MultiplicativeGroup`]: Multiplication of non-zero elements
+//! - [`AffineGroup`]: Composition of invertible affine transformations
+//! - [`PermutationGroup`]: Composition of permutations
+
+use super::group::Group;
+use super::property::Commutative;
+use crate::math::gf::GF;
+
+use std::marker::PhantomData;
+
+/// Additive group over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a + b`
+/// - **identity**: `0`
+/// - **inverse**: `-a`
+///
+/// Integer types use wrapping arithmetic, so they form the group `ℤ/2^k ℤ`.
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+/// - `GF<MOD>`
+pub struct AdditiveGroup<T>(PhantomData<T>);
+
+/// Implements [`Group`] for [`AdditiveGroup<T>`] for multiple integer types.
+macro_rules! impl_additive_group {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Group for AdditiveGroup<$t> {
+                type G = $t;
+
+                #[inline]
+                fn identity() -> Self::G {
+                    0
+                }
+
+                #[inline]
+                fn op(a: &Self::G, b: &Self::G) -> Self::G {
+                    a.wrapping_add(*b)
+                }
+
+                #[inline]
+                fn inv(a: &Self::G) -> Self::G {
+                    a.wrapping_neg()
+                }
+
+                #[inline]
+                fn div(a: &Self::G, b: &Self::G) -> Self::G {
+                    a.wrapping_sub(*b)
+                }
+            }
+        )*
+    };
+}
+impl_additive_group!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Implements [`Group`] for [`AdditiveGroup<T>`] for floating point types.
+macro_rules! impl_additive_group_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Group for AdditiveGroup<$t> {
+                type G = $t;
+
+                #[inline]
+                fn identity() -> Self::G {
+                    0.0
+                }
+
+                #[inline]
+                fn op(a: &Self::G, b: &Self::G) -> Self::G {
+                    a + b
+                }
+
+                #[inline]
+                fn inv(a: &Self::G) -> Self::G {
+                    -a
+                }
+
+                #[inline]
+                fn div(a: &Self::G, b: &Self::G) -> Self::G {
+                    a - b
+                }
+            }
+        )*
+    };
+}
+impl_additive_group_float!(f32, f64);
+
+impl<const MOD: u32> Group for AdditiveGroup<GF<MOD>> {
+    type G = GF<MOD>;
+
+    #[inline]
+    fn identity() -> Self::G {
+        GF::zero()
+    }
+
+    #[inline]
+    fn op(a: &Self::G, b: &Self::G) -> Self::G {
+        a + b
+    }
+
+    #[inline]
+    fn inv(a: &Self::G) -> Self::G {
+        -*a
+    }
+
+    #[inline]
+    fn div(a: &Self::G, b: &Self::G) -> Self::G {
+        a - b
+    }
+}
+
+impl<T> Commutative for AdditiveGroup<T> {}
+
+/// Bitwise xor group over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a ^ b`
+/// - **identity**: `0`
+/// - **inverse**: `a` itself
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+pub struct XorGroup<T>(PhantomData<T>);
+
+/// Implements [`Group`] for [`XorGroup<T>`] for multiple integer types.
+macro_rules! impl_xor_group {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Group for XorGroup<$t> {
+                type G = $t;
+
+                #[inline]
+                fn identity() -> Self::G {
+                    0
+                }
+
+                #[inline]
+                fn op(a: &Self::G, b: &Self::G) -> Self::G {
+                    a ^ b
+                }
+
+                #[inline]
+                fn inv(a: &Self::G) -> Self::G {
+                    *a
+                }
+            }
+        )*
+    };
+}
+impl_xor_group!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<T> Commutative for XorGroup<T> {}
+
+/// Multiplicative group over type `T`.
+///
+/// - **set**: Non-zero elements of `T`
+/// - **operation**: `a * b`
+/// - **identity**: `1`
+/// - **inverse**: `a^(-1)`
+///
+/// # Supported Types
+///
+/// - `f32`, `f64`
+/// - `GF<MOD>`
+pub struct MultiplicativeGroup<T>(PhantomData<T>);
+
+/// Implements [`Group`] for [`MultiplicativeGroup<T>`] for floating point types.
+macro_rules! impl_multiplicative_group_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Group for MultiplicativeGroup<$t> {
+                type G = $t;
+
+                #[inline]
+                fn identity() -> Self::G {
+                    1.0
+                }
+
+                #[inline]
+                fn op(a: &Self::G, b: &Self::G) -> Self::G {
+                    a * b
+                }
+
+                #[inline]
+                fn inv(a: &Self::G) -> Self::G {
+                    a.recip()
+                }
+
+                #[inline]
+                fn div(a: &Self::G, b: &Self::G) -> Self::G {
+                    a / b
+                }
+            }
+        )*
+    };
+}
+impl_multiplicative_group_float!(f32, f64);
+
+impl<const MOD: u32> Group for MultiplicativeGroup<GF<MOD>> {
+    type G = GF<MOD>;
+
+    #[inline]
+    fn identity() -> Self::G {
+        GF::one()
+    }
+
+    #[inline]
+    fn op(a: &Self::G, b: &Self::G) -> Self::G {
+        a * b
+    }
+
+    #[inline]
+    fn inv(a: &Self::G) -> Self::G {
+        a.inv()
+    }
+
+    #[inline]
+    fn div(a: &Self::G, b: &Self::G) -> Self::G {
+        a / b
+    }
+}
+
+impl<T> Commutative for MultiplicativeGroup<T> {}
+
+/// Invertible affine transformation composition group over type `T`.
+/// Represents affine functions `f(x) = ax + b` with `a != 0` as tuples `(a, b)`.
+///
+/// - **set**: `(T, T)` representing `f(x) = ax + b` with `a != 0`.
+/// - **operation**: Function composition `g ∘ f`.
+/// - **identity**: `(1, 0)` representing the identity function `f(x) = x`.
+/// - **inverse**: `(a^(-1), -b * a^(-1))` representing `f^(-1)(x) = (x - b) / a`.
+///
+/// # Composition Order
+///
+/// Same as [`AffineMonoid`](super::monoid_impl::AffineMonoid):
+/// `op(&a, &b)` computes `b ∘ a`, which applies `a` first, then `b`.
+///
+/// # Supported Types
+///
+/// - `f32`, `f64`
+/// - `GF<MOD>`
+pub struct AffineGroup<T>(PhantomData<T>);
+
+/// Implements [`Group`] for [`AffineGroup<T>`] for floating point types.
+macro_rules! impl_affine_group_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Group for AffineGroup<$t> {
+                type G = ($t, $t);
+
+                #[inline]
+                fn identity() -> Self::G {
+                    (1.0, 0.0)
+                }
+
+                #[inline]
+                fn op(a: &Self::G, b: &Self::G) -> Self::G {
+                    (a.0 * b.0, a.1 * b.0 + b.1)
+                }
+
+                #[inline]
+                fn inv(a: &Self::G) -> Self::G {
+                    (a.0.recip(), -a.1 / a.0)
+                }
+            }
+        )*
+    };
+}
+impl_affine_group_float!(f32, f64);
+
+impl<const MOD: u32> Group for AffineGroup<GF<MOD>> {
+    type G = (GF<MOD>, GF<MOD>);
+
+    #[inline]
+    fn identity() -> Self::G {
+        (GF::one(), GF::zero())
+    }
+
+    #[inline]
+    fn op(a: &Self::G, b: &Self::G) -> Self::G {
+        (a.0 * b.0, a.1 * b.0 + b.1)
+    }
+
+    #[inline]
+    fn inv(a: &Self::G) -> Self::G {
+        let inv = a.0.inv();
+        (inv, -a.1 * inv)
+    }
+}
+
+/// Permutation composition group on `N` points.
+///
+/// A permutation `p` maps `i` to `p[i]`.
+///
+/// - **set**: `[usize; N]`, permutations of `0..N`.
+/// - **operation**: Composition `q ∘ p`.
+/// - **identity**: `[0, 1, ..., N - 1]`.
+/// - **inverse**: `p^(-1)` with `p^(-1)[p[i]] = i`.
+///
+/// # Composition Order
+///
+/// `op(&p, &q)` computes `q ∘ p`, which applies `p` first, then `q`:
+/// `op(&p, &q)[i] = q[p[i]]`.
+pub struct PermutationGroup<const N: usize>;
+
+impl<const N: usize> Group for PermutationGroup<N> {
+    type G = [usize; N];
+
+    #[inline]
+    fn identity() -> Self::G {
+        std::array::from_fn(|i| i)
+    }
+
+    #[inline]
+    fn op(a: &Self::G, b: &Self::G) -> Self::G {
+        std::array::from_fn(|i| b[a[i]])
+    }
+
+    #[inline]
+    fn inv(a: &Self::G) -> Self::G {
+        let mut res = [0; N];
+        for (i, &p) in a.iter().enumerate() {
+            res[p] = i;
+        }
+        res
+    }
+}
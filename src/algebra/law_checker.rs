@@ -0,0 +1,571 @@
+//! Law checker
+//!
+//! Randomized checks that an implementation of the `algebra` traits satisfies its laws.
+//!
+//! Each `check_*` function draws values from a user-supplied generator, evaluates both sides of
+//! every law and returns the first [`Counterexample`] found. All comparisons use `PartialEq`, so
+//! generators for floating point types should produce values without rounding errors
+//! (e.g. small integers).
+//!
+//! The checks use a fixed seed, so a reported counterexample is reproducible.
+//!
+//! # Available functions
+//!
+//! - [`check_semigroup`]: Associativity of a semigroup operation
+//! - [`check_monoid`]: Associativity and identity
+//! - [`check_group`]: Monoid laws and inverse
+//! - [`check_commutative`]: Commutativity of a monoid operation
+//! - [`check_idempotent`]: Idempotency of a monoid operation
+//! - [`check_monoid_action`]: Monoid laws of both sets, identity action, homomorphism and
+//!   composition compatibility
+//! - [`check_semiring`], [`check_ring`], [`check_field`]: Semiring, ring and field axioms
+
+use super::group::Group;
+use super::monoid::Monoid;
+use super::monoid_action::MonoidAction;
+use super::semigroup::Semigroup;
+use super::semiring::{Field, Ring, Semiring};
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// Seed of the random number generator used by the checks.
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// A *xorshift* pseudo random number generator (xorshift64).
+///
+/// It is passed to the value generators of the `check_*` functions.
+#[derive(Clone, Debug)]
+pub struct XorShift {
+    /// Internal state (never `0`).
+    state: u64,
+}
+
+impl XorShift {
+    /// Creates a new generator from `seed`.
+    ///
+    /// A seed of `0` is replaced by a fixed non-zero value.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { SEED } else { seed },
+        }
+    }
+
+    /// Returns the next pseudo random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a pseudo random value in `0..n`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n == 0`.
+    pub fn range(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "n must be positive");
+        self.next_u64() % n
+    }
+}
+
+/// A violation of an algebraic law.
+///
+/// Its [`Display`] output names the law and shows the inputs and both sides of the equation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counterexample {
+    /// The name of the violated law, e.g. `"associativity"`.
+    pub law: &'static str,
+
+    /// The inputs and both sides of the violated equation.
+    pub detail: String,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} violated: {}", self.law, self.detail)
+    }
+}
+
+impl std::error::Error for Counterexample {}
+
+/// Returns a counterexample if `lhs != rhs`.
+fn expect_eq<T: PartialEq + Debug>(
+    law: &'static str,
+    inputs: &[(&str, &dyn Debug)],
+    lhs: (&str, T),
+    rhs: (&str, T),
+) -> Result<(), Counterexample> {
+    if lhs.1 == rhs.1 {
+        return Ok(());
+    }
+    let mut detail = String::new();
+    for (name, value) in inputs {
+        detail += &format!("{} = {:?}, ", name, value);
+    }
+    detail += &format!("{} = {:?}, {} = {:?}", lhs.0, lhs.1, rhs.0, rhs.1);
+    Err(Counterexample { law, detail })
+}
+
+/// Checks `op(op(a, b), c) = op(a, op(b, c))`.
+fn associativity<T: PartialEq + Debug>(
+    law: &'static str,
+    op: impl Fn(&T, &T) -> T,
+    a: &T,
+    b: &T,
+    c: &T,
+) -> Result<(), Counterexample> {
+    expect_eq(
+        law,
+        &[("a", a), ("b", b), ("c", c)],
+        ("(a * b) * c", op(&op(a, b), c)),
+        ("a * (b * c)", op(a, &op(b, c))),
+    )
+}
+
+/// Checks `op(a, e) = op(e, a) = a`.
+fn identity<T: Clone + PartialEq + Debug>(
+    law: &'static str,
+    op: impl Fn(&T, &T) -> T,
+    e: &T,
+    a: &T,
+) -> Result<(), Counterexample> {
+    let inputs: [(&str, &dyn Debug); 2] = [("a", a), ("e", e)];
+    expect_eq(law, &inputs, ("a * e", op(a, e)), ("a", a.clone()))?;
+    expect_eq(law, &inputs, ("e * a", op(e, a)), ("a", a.clone()))
+}
+
+/// Checks that `S` with `op` is a semigroup on `trials` random triples.
+///
+/// ## Laws
+///
+/// - **associativity**: `op(op(a, b), c) = op(a, op(b, c))`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_semigroup<S: Semigroup>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> S::S,
+) -> Result<(), Counterexample>
+where
+    S::S: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    for _ in 0..trials {
+        let (a, b, c) = (generate(&mut rng), generate(&mut rng), generate(&mut rng));
+        associativity("associativity", S::op, &a, &b, &c)?;
+    }
+    Ok(())
+}
+
+/// Checks that `M` is a monoid on `trials` random triples.
+///
+/// ## Laws
+///
+/// - **associativity**: `op(op(a, b), c) = op(a, op(b, c))`
+/// - **identity**: `op(a, e) = op(e, a) = a`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_monoid<M: Monoid>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> M::S,
+) -> Result<(), Counterexample>
+where
+    M::S: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    let e = M::identity();
+    for _ in 0..trials {
+        let (a, b, c) = (generate(&mut rng), generate(&mut rng), generate(&mut rng));
+        associativity("associativity", M::op, &a, &b, &c)?;
+        identity("identity", M::op, &e, &a)?;
+    }
+    Ok(())
+}
+
+/// Checks that `G` is a group on `trials` random triples.
+///
+/// ## Laws
+///
+/// - **associativity**: `op(op(a, b), c) = op(a, op(b, c))`
+/// - **identity**: `op(a, e) = op(e, a) = a`
+/// - **inverse**: `op(a, inv(a)) = op(inv(a), a) = e`
+/// - **division**: `div(a, b) = op(a, inv(b))`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_group<G: Group>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> G::G,
+) -> Result<(), Counterexample>
+where
+    G::G: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    let e = G::identity();
+    for _ in 0..trials {
+        let (a, b, c) = (generate(&mut rng), generate(&mut rng), generate(&mut rng));
+        associativity("associativity", G::op, &a, &b, &c)?;
+        identity("identity", G::op, &e, &a)?;
+        let inv = G::inv(&a);
+        let inputs: [(&str, &dyn Debug); 2] = [("a", &a), ("inv(a)", &inv)];
+        expect_eq(
+            "inverse",
+            &inputs,
+            ("a * inv(a)", G::op(&a, &inv)),
+            ("e", e.clone()),
+        )?;
+        expect_eq(
+            "inverse",
+            &inputs,
+            ("inv(a) * a", G::op(&inv, &a)),
+            ("e", e.clone()),
+        )?;
+        expect_eq(
+            "division",
+            &[("a", &a), ("b", &b)],
+            ("div(a, b)", G::div(&a, &b)),
+            ("a * inv(b)", G::op(&a, &G::inv(&b))),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that the operation of `M` is commutative on `trials` random pairs.
+///
+/// Use this to confirm a [`Commutative`](super::property::Commutative) marker.
+///
+/// ## Laws
+///
+/// - **commutativity**: `op(a, b) = op(b, a)`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_commutative<M: Monoid>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> M::S,
+) -> Result<(), Counterexample>
+where
+    M::S: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    for _ in 0..trials {
+        let (a, b) = (generate(&mut rng), generate(&mut rng));
+        expect_eq(
+            "commutativity",
+            &[("a", &a), ("b", &b)],
+            ("a * b", M::op(&a, &b)),
+            ("b * a", M::op(&b, &a)),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that the operation of `M` is idempotent on `trials` random values.
+///
+/// Use this to confirm an [`Idempotent`](super::property::Idempotent) marker.
+///
+/// ## Laws
+///
+/// - **idempotency**: `op(a, a) = a`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_idempotent<M: Monoid>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> M::S,
+) -> Result<(), Counterexample>
+where
+    M::S: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    for _ in 0..trials {
+        let a = generate(&mut rng);
+        expect_eq(
+            "idempotency",
+            &[("a", &a)],
+            ("a * a", M::op(&a, &a)),
+            ("a", a.clone()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that `A` is a monoid action on `trials` random samples.
+///
+/// ## Laws
+///
+/// - **associativity** and **identity** of both `op_s` and `op_f`
+/// - **identity action**: `apply(x, e_F) = x`
+/// - **homomorphism**: `apply(op_s(a, b), f) = op_s(apply(a, f), apply(b, f))`
+/// - **composition**: `apply(apply(x, f), g) = apply(x, op_f(f, g))`
+///
+/// The composition order is the one used by
+/// [`LazySegTree`](crate::ds::lazy_segtree::LazySegTree): `op_f(f, g)` applies `f` first, then
+/// `g`.
+///
+/// ## Parameters
+///
+/// - `trials`: The number of random samples
+/// - `generate_s`: Generator of values in `S`
+/// - `generate_f`: Generator of operators in `F`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+///
+/// ## Examples
+///
+/// ```
+/// use math_optim::algebra::law_checker::{XorShift, check_monoid_action};
+/// use math_optim::algebra::monoid_action::MonoidAction;
+/// use math_optim::algebra::monoid_action_impl::AffineSumAction;
+/// use math_optim::math::gf::GF;
+///
+/// type Mint = GF<998244353>;
+/// type Affine = AffineSumAction<Mint>;
+///
+/// let gf = |rng: &mut XorShift| Mint::new(rng.range(998244353) as u32);
+/// let s = |rng: &mut XorShift| (gf(rng), gf(rng));
+/// let f = |rng: &mut XorShift| (gf(rng), gf(rng));
+/// assert_eq!(check_monoid_action::<Affine>(100, s, f), Ok(()));
+///
+/// // The same action with the composition order of `op_f` reversed.
+/// struct Swapped;
+///
+/// impl MonoidAction for Swapped {
+///     type S = (Mint, Mint);
+///     type F = (Mint, Mint);
+///     fn identity_s() -> Self::S {
+///         Affine::identity_s()
+///     }
+///     fn identity_f() -> Self::F {
+///         Affine::identity_f()
+///     }
+///     fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+///         Affine::op_s(a, b)
+///     }
+///     fn op_f(f: &Self::F, g: &Self::F) -> Self::F {
+///         Affine::op_f(g, f)
+///     }
+///     fn apply(x: &mut Self::S, f: &Self::F) {
+///         Affine::apply(x, f)
+///     }
+/// }
+///
+/// let err = check_monoid_action::<Swapped>(100, s, f).unwrap_err();
+/// assert_eq!(err.law, "composition");
+/// assert!(err.to_string().starts_with("composition violated: x = "));
+/// ```
+pub fn check_monoid_action<A: MonoidAction>(
+    trials: usize,
+    mut generate_s: impl FnMut(&mut XorShift) -> A::S,
+    mut generate_f: impl FnMut(&mut XorShift) -> A::F,
+) -> Result<(), Counterexample>
+where
+    A::S: Clone + PartialEq + Debug,
+    A::F: PartialEq + Debug,
+{
+    let apply = |x: &A::S, f: &A::F| {
+        let mut x = x.clone();
+        A::apply(&mut x, f);
+        x
+    };
+    let mut rng = XorShift::new(SEED);
+    let (e_s, e_f) = (A::identity_s(), A::identity_f());
+    for _ in 0..trials {
+        let (a, b, c) = (
+            generate_s(&mut rng),
+            generate_s(&mut rng),
+            generate_s(&mut rng),
+        );
+        let (f, g, h) = (
+            generate_f(&mut rng),
+            generate_f(&mut rng),
+            generate_f(&mut rng),
+        );
+        associativity("associativity of op_s", A::op_s, &a, &b, &c)?;
+        identity("identity of op_s", A::op_s, &e_s, &a)?;
+        associativity("associativity of op_f", A::op_f, &f, &g, &h)?;
+        identity("identity of op_f", A::op_f, &e_f, &f)?;
+        expect_eq(
+            "identity action",
+            &[("x", &a)],
+            ("apply(x, e_F)", apply(&a, &e_f)),
+            ("x", a.clone()),
+        )?;
+        expect_eq(
+            "homomorphism",
+            &[("a", &a), ("b", &b), ("f", &f)],
+            ("apply(a * b, f)", apply(&A::op_s(&a, &b), &f)),
+            (
+                "apply(a, f) * apply(b, f)",
+                A::op_s(&apply(&a, &f), &apply(&b, &f)),
+            ),
+        )?;
+        expect_eq(
+            "composition",
+            &[("x", &a), ("f", &f), ("g", &g)],
+            ("apply(apply(x, f), g)", apply(&apply(&a, &f), &g)),
+            ("apply(x, op_f(f, g))", apply(&a, &A::op_f(&f, &g))),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that `R` is a semiring on `trials` random triples.
+///
+/// ## Laws
+///
+/// - **associativity**, **commutativity** and **identity** of `add`
+/// - **associativity** and **identity** of `mul`
+/// - **distributivity**: `a * (b + c) = a * b + a * c` and `(a + b) * c = a * c + b * c`
+/// - **annihilation**: `0 * a = a * 0 = 0`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_semiring<R: Semiring>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> R::S,
+) -> Result<(), Counterexample>
+where
+    R::S: PartialEq + Debug,
+{
+    let mut rng = XorShift::new(SEED);
+    let (zero, one) = (R::zero(), R::one());
+    for _ in 0..trials {
+        let (a, b, c) = (generate(&mut rng), generate(&mut rng), generate(&mut rng));
+        associativity("associativity of add", R::add, &a, &b, &c)?;
+        identity("identity of add", R::add, &zero, &a)?;
+        expect_eq(
+            "commutativity of add",
+            &[("a", &a), ("b", &b)],
+            ("a + b", R::add(&a, &b)),
+            ("b + a", R::add(&b, &a)),
+        )?;
+        associativity("associativity of mul", R::mul, &a, &b, &c)?;
+        identity("identity of mul", R::mul, &one, &a)?;
+        let inputs: [(&str, &dyn Debug); 3] = [("a", &a), ("b", &b), ("c", &c)];
+        expect_eq(
+            "left distributivity",
+            &inputs,
+            ("a * (b + c)", R::mul(&a, &R::add(&b, &c))),
+            ("a * b + a * c", R::add(&R::mul(&a, &b), &R::mul(&a, &c))),
+        )?;
+        expect_eq(
+            "right distributivity",
+            &inputs,
+            ("(a + b) * c", R::mul(&R::add(&a, &b), &c)),
+            ("a * c + b * c", R::add(&R::mul(&a, &c), &R::mul(&b, &c))),
+        )?;
+        let inputs: [(&str, &dyn Debug); 1] = [("a", &a)];
+        expect_eq(
+            "annihilation",
+            &inputs,
+            ("0 * a", R::mul(&zero, &a)),
+            ("0", zero.clone()),
+        )?;
+        expect_eq(
+            "annihilation",
+            &inputs,
+            ("a * 0", R::mul(&a, &zero)),
+            ("0", zero.clone()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that `R` is a ring on `trials` random triples.
+///
+/// ## Laws
+///
+/// - The semiring axioms (see [`check_semiring`])
+/// - **additive inverse**: `a + neg(a) = 0`
+/// - **subtraction**: `sub(a, b) = a + neg(b)`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_ring<R: Ring>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> R::S,
+) -> Result<(), Counterexample>
+where
+    R::S: PartialEq + Debug,
+{
+    check_semiring::<R>(trials, &mut generate)?;
+    let mut rng = XorShift::new(SEED);
+    for _ in 0..trials {
+        let (a, b) = (generate(&mut rng), generate(&mut rng));
+        expect_eq(
+            "additive inverse",
+            &[("a", &a)],
+            ("a + neg(a)", R::add(&a, &R::neg(&a))),
+            ("0", R::zero()),
+        )?;
+        expect_eq(
+            "subtraction",
+            &[("a", &a), ("b", &b)],
+            ("sub(a, b)", R::sub(&a, &b)),
+            ("a + neg(b)", R::add(&a, &R::neg(&b))),
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that `R` is a field on `trials` random triples.
+///
+/// ## Laws
+///
+/// - The ring axioms (see [`check_ring`])
+/// - **commutativity** of `mul`
+/// - **multiplicative inverse**: `a * inv(a) = 1` for `a != 0`
+/// - **division**: `div(a, b) = a * inv(b)` for `b != 0`
+///
+/// ## Returns
+///
+/// The first [`Counterexample`] found, or `Ok(())`.
+pub fn check_field<R: Field>(
+    trials: usize,
+    mut generate: impl FnMut(&mut XorShift) -> R::S,
+) -> Result<(), Counterexample>
+where
+    R::S: PartialEq + Debug,
+{
+    check_ring::<R>(trials, &mut generate)?;
+    let mut rng = XorShift::new(SEED);
+    let zero = R::zero();
+    for _ in 0..trials {
+        let (a, b) = (generate(&mut rng), generate(&mut rng));
+        expect_eq(
+            "commutativity of mul",
+            &[("a", &a), ("b", &b)],
+            ("a * b", R::mul(&a, &b)),
+            ("b * a", R::mul(&b, &a)),
+        )?;
+        if a != zero {
+            expect_eq(
+                "multiplicative inverse",
+                &[("a", &a)],
+                ("a * inv(a)", R::mul(&a, &R::inv(&a))),
+                ("1", R::one()),
+            )?;
+        }
+        if b != zero {
+            expect_eq(
+                "division",
+                &[("a", &a), ("b", &b)],
+                ("div(a, b)", R::div(&a, &b)),
+                ("a * inv(b)", R::mul(&a, &R::inv(&b))),
+            )?;
+        }
+    }
+    Ok(())
+}
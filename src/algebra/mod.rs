@@ -1,4 +1,12 @@
 pub mod group;
+pub mod group_impl;
+pub mod law_checker;
 pub mod monoid;
 pub mod monoid_action;
+pub mod monoid_action_impl;
+pub mod monoid_combinator;
 pub mod monoid_impl;
+pub mod property;
+pub mod semigroup;
+pub mod semiring;
+pub mod semiring_impl;
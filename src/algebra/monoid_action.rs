@@ -9,7 +9,8 @@
 //! - An operator monoid `(F, *, e_F)`
 //! - An action `F x S -> S` that satisfies:
 //!   - **Homomorphism**: `f(a * b) = f(a) * f(b)` for all `f` in `F` and `a, b` in `S`.
-//!   - **Composition compatibility**: `(f * g)(x) = f(g(x))` for all `f, g` in `F`.
+//!   - **Composition compatibility**: `(f * g)(x) = g(f(x))` for all `f, g` in `F`, i.e. `f * g`
+//!     applies `f` first, then `g`.
 //!   - **Identity**: `e(x) = x` for all `x` in `S`.
 
 /// A trait representing a *monoid action*.
@@ -0,0 +1,376 @@
+//! Standard implementations of monoid actions.
+//!
+//! This module provides commonly used monoid action implementations.
+//!
+//! # Available monoid actions
+//!
+//! - [`AddSumAction`]: Range add, range sum
+//! - [`AddMinAction`]: Range add, range minimum
+//! - [`AddMaxAction`]: Range add, range maximum
+//! - [`AssignSumAction`]: Range assign, range sum
+//! - [`AssignMinAction`]: Range assign, range minimum
+//! - [`AssignMaxAction`]: Range assign, range maximum
+//! - [`AffineSumAction`]: Range affine transformation, range sum
+//!
+//! # Composition Order
+//!
+//! As in [`AffineMonoid`](super::monoid_impl::AffineMonoid), `op_f(&f, &g)` represents the
+//! operator applying `f` first, then `g`.
+//!
+//! # Sum Actions
+//!
+//! Actions on sums need the length of the range, so their value set is `(sum, len)`.
+//! A single element `x` is represented by `(x, 1)`.
+
+use super::monoid_action::MonoidAction;
+use crate::math::gf::GF;
+
+use std::marker::PhantomData;
+
+/// Range add, range sum over type `T`.
+///
+/// - **value**: `(sum, len)` with `(0, 0)` as identity.
+/// - **operator**: `c` adding `c` to each element, with `0` as identity.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+/// - `f64`
+/// - `GF<MOD>`
+pub struct AddSumAction<T>(PhantomData<T>);
+
+/// Range assign, range sum over type `T`.
+///
+/// - **value**: `(sum, len)` with `(0, 0)` as identity.
+/// - **operator**: `Some(c)` assigning `c` to each element, with `None` as identity.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+/// - `f64`
+/// - `GF<MOD>`
+pub struct AssignSumAction<T>(PhantomData<T>);
+
+/// Range affine transformation, range sum over type `T`.
+///
+/// - **value**: `(sum, len)` with `(0, 0)` as identity.
+/// - **operator**: `(a, b)` replacing each element `x` with `ax + b`, with `(1, 0)` as identity.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+/// - `f64`
+/// - `GF<MOD>`
+pub struct AffineSumAction<T>(PhantomData<T>);
+
+/// Implements the sum actions for a type with the given `0` and `1`.
+macro_rules! impl_sum_action {
+    ($([$($g:tt)*] $t:ty => $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl<$($g)*> MonoidAction for AddSumAction<$t> {
+                type S = ($t, $t);
+                type F = $t;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    ($zero, $zero)
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    $zero
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    (a.0 + b.0, a.1 + b.1)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    *a + *b
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    x.0 += *f * x.1;
+                }
+            }
+
+            impl<$($g)*> MonoidAction for AssignSumAction<$t> {
+                type S = ($t, $t);
+                type F = Option<$t>;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    ($zero, $zero)
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    None
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    (a.0 + b.0, a.1 + b.1)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    b.or(*a)
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    if let Some(c) = f {
+                        x.0 = *c * x.1;
+                    }
+                }
+            }
+
+            impl<$($g)*> MonoidAction for AffineSumAction<$t> {
+                type S = ($t, $t);
+                type F = ($t, $t);
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    ($zero, $zero)
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    ($one, $zero)
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    (a.0 + b.0, a.1 + b.1)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    (a.0 * b.0, a.1 * b.0 + b.1)
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    x.0 = f.0 * x.0 + f.1 * x.1;
+                }
+            }
+        )*
+    };
+}
+impl_sum_action!(
+    [] i32 => 0, 1;
+    [] i64 => 0, 1;
+    [] i128 => 0, 1;
+    [] isize => 0, 1;
+    [] u32 => 0, 1;
+    [] u64 => 0, 1;
+    [] u128 => 0, 1;
+    [] usize => 0, 1;
+    [] f64 => 0.0, 1.0;
+    [const MOD: u32] GF<MOD> => GF::zero(), GF::one();
+);
+
+/// Range add, range minimum over type `T`.
+///
+/// - **value**: The minimum, with `T::MAX` as identity.
+/// - **operator**: `c` adding `c` to each element, with `0` as identity.
+///
+/// # Requirements
+///
+/// **Every value must be smaller than `T::MAX`**, also after additions. `T::MAX` is reserved
+/// for the identity (the minimum of an empty range) and [`apply`](MonoidAction::apply) leaves it
+/// unchanged so that empty ranges stay empty, so a stored `T::MAX` ignores every addition.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+pub struct AddMinAction<T>(PhantomData<T>);
+
+/// Range add, range maximum over type `T`.
+///
+/// - **value**: The maximum, with `T::MIN` as identity.
+/// - **operator**: `c` adding `c` to each element, with `0` as identity.
+///
+/// # Requirements
+///
+/// **Every value must be greater than `T::MIN`**, also after additions. `T::MIN` is reserved
+/// for the identity (the maximum of an empty range) and [`apply`](MonoidAction::apply) leaves it
+/// unchanged so that empty ranges stay empty, so a stored `T::MIN` ignores every addition.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+pub struct AddMaxAction<T>(PhantomData<T>);
+
+/// Range assign, range minimum over type `T`.
+///
+/// - **value**: The minimum, with `T::MAX` as identity.
+/// - **operator**: `Some(c)` assigning `c` to each element, with `None` as identity.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+pub struct AssignMinAction<T>(PhantomData<T>);
+
+/// Range assign, range maximum over type `T`.
+///
+/// - **value**: The maximum, with `T::MIN` as identity.
+/// - **operator**: `Some(c)` assigning `c` to each element, with `None` as identity.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+pub struct AssignMaxAction<T>(PhantomData<T>);
+
+/// Implements [`AddMinAction<T>`] and [`AddMaxAction<T>`] for multiple signed integer types.
+macro_rules! impl_add_min_max_action {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl MonoidAction for AddMinAction<$t> {
+                type S = $t;
+                type F = $t;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    <$t>::MAX
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    0
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.min(b)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    a + b
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    if *x != <$t>::MAX {
+                        *x += f;
+                    }
+                }
+            }
+
+            impl MonoidAction for AddMaxAction<$t> {
+                type S = $t;
+                type F = $t;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    <$t>::MIN
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    0
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.max(b)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    a + b
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    if *x != <$t>::MIN {
+                        *x += f;
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_add_min_max_action!(i32, i64, i128, isize);
+
+/// Implements [`AssignMinAction<T>`] and [`AssignMaxAction<T>`] for multiple integer types.
+macro_rules! impl_assign_min_max_action {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl MonoidAction for AssignMinAction<$t> {
+                type S = $t;
+                type F = Option<$t>;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    <$t>::MAX
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    None
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.min(b)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    b.or(*a)
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    if let Some(c) = f {
+                        *x = *c;
+                    }
+                }
+            }
+
+            impl MonoidAction for AssignMaxAction<$t> {
+                type S = $t;
+                type F = Option<$t>;
+
+                #[inline]
+                fn identity_s() -> Self::S {
+                    <$t>::MIN
+                }
+
+                #[inline]
+                fn identity_f() -> Self::F {
+                    None
+                }
+
+                #[inline]
+                fn op_s(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.max(b)
+                }
+
+                #[inline]
+                fn op_f(a: &Self::F, b: &Self::F) -> Self::F {
+                    b.or(*a)
+                }
+
+                #[inline]
+                fn apply(x: &mut Self::S, f: &Self::F) {
+                    if let Some(c) = f {
+                        *x = *c;
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_assign_min_max_action!(i32, i64, i128, isize, u32, u64, u128, usize);
@@ -0,0 +1,100 @@
+//! Monoid combinators
+//!
+//! This module provides generic constructions of new monoids from existing ones.
+//!
+//! # Available combinators
+//!
+//! - [`Pair`]: Direct product of two monoids
+//! - [`Reversed`]: Opposite monoid with the reversed operation
+//! - [`OptionMonoid`]: Monoid from a semigroup by adjoining an identity
+//!
+//! A [`Group`](super::group::Group) is already a [`Monoid`], so it can be combined as well.
+
+use super::monoid::Monoid;
+use super::property::{Commutative, Idempotent};
+use super::semigroup::Semigroup;
+
+use std::marker::PhantomData;
+
+/// Direct product of monoids `A` and `B`.
+///
+/// - **set**: `(A::S, B::S)`
+/// - **operation**: `(a1, b1) * (a2, b2) = (a1 * a2, b1 * b2)`
+/// - **identity**: `(A::identity(), B::identity())`
+///
+/// This is useful to fold two quantities over the same range at once, e.g. the sum and the
+/// minimum.
+pub struct Pair<A, B>(PhantomData<(A, B)>);
+
+impl<A: Monoid, B: Monoid> Monoid for Pair<A, B> {
+    type S = (A::S, B::S);
+
+    #[inline]
+    fn identity() -> Self::S {
+        (A::identity(), B::identity())
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        (A::op(&a.0, &b.0), B::op(&a.1, &b.1))
+    }
+}
+
+impl<A: Commutative, B: Commutative> Commutative for Pair<A, B> {}
+impl<A: Idempotent, B: Idempotent> Idempotent for Pair<A, B> {}
+
+/// Opposite monoid of `M`.
+///
+/// - **set**: `M::S`
+/// - **operation**: `a * b = M::op(b, a)`
+/// - **identity**: `M::identity()`
+///
+/// Folding a range with `Reversed<M>` gives the fold of the reversed range with `M`. On trees,
+/// a path query `u -> v` through the lowest common ancestor folds the upward part with
+/// `Reversed<M>` and the downward part with `M`.
+pub struct Reversed<M>(PhantomData<M>);
+
+impl<M: Monoid> Monoid for Reversed<M> {
+    type S = M::S;
+
+    #[inline]
+    fn identity() -> Self::S {
+        M::identity()
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        M::op(b, a)
+    }
+}
+
+impl<M: Commutative> Commutative for Reversed<M> {}
+impl<M: Idempotent> Idempotent for Reversed<M> {}
+
+/// Monoid from a semigroup `T` by adjoining an identity element.
+///
+/// - **set**: `Option<T::S>`, where `None` is the adjoined identity.
+/// - **operation**: `Some(a) * Some(b) = Some(T::op(a, b))`, and `None` is the identity.
+/// - **identity**: `None`
+pub struct OptionMonoid<T>(PhantomData<T>);
+
+impl<T: Semigroup> Monoid for OptionMonoid<T> {
+    type S = Option<T::S>;
+
+    #[inline]
+    fn identity() -> Self::S {
+        None
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(T::op(a, b)),
+            (Some(a), None) => Some(a.clone()),
+            (None, b) => b.clone(),
+        }
+    }
+}
+
+impl<T: Commutative> Commutative for OptionMonoid<T> {}
+impl<T: Idempotent> Idempotent for OptionMonoid<T> {}
@@ -5,8 +5,22 @@
 //! # Available monoids
 //!
 //! - [`AffineMonoid`]: Composition of affine transformations
+//! - [`SumMonoid`]: Sum of values
+//! - [`ProductMonoid`]: Product of values
+//! - [`MinMonoid`]: Minimum of values
+//! - [`MaxMonoid`]: Maximum of values
+//! - [`GcdMonoid`]: Greatest common divisor
+//! - [`XorMonoid`]: Bitwise xor
+//! - [`AndMonoid`]: Bitwise and
+//! - [`OrMonoid`]: Bitwise or
+//! - [`MaxSubarraySumMonoid`]: Maximum sum of a non-empty contiguous subsequence
+//! - [`FirstOfMonoid`]: The first present value
+//! - [`LastOfMonoid`]: The last present value
 
 use super::monoid::Monoid;
+use super::property::{Commutative, Idempotent};
+use crate::math::gcd::gcd;
+use crate::math::gf::GF;
 
 use std::marker::PhantomData;
 
@@ -33,6 +47,7 @@ use std::marker::PhantomData;
 ///
 /// - `i32`, `i64`, `i128`
 /// - `u32`, `u64`, `u128`
+/// - `GF<MOD>`
 pub struct AffineMonoid<T>(PhantomData<T>);
 /// Implements [`Monoid`] for [`AffineMonoid<T>`] for multiple integer types.
 macro_rules! impl_affine_monoid {
@@ -55,3 +70,415 @@ macro_rules! impl_affine_monoid {
     };
 }
 impl_affine_monoid!(i32, i64, i128, u32, u64, u128);
+
+impl<const MOD: u32> Monoid for AffineMonoid<GF<MOD>> {
+    type S = (GF<MOD>, GF<MOD>);
+
+    #[inline]
+    fn identity() -> Self::S {
+        (GF::one(), GF::zero())
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        (a.0 * b.0, a.1 * b.0 + b.1)
+    }
+}
+
+/// Addition monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a + b`
+/// - **identity**: `0`
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+/// - `GF<MOD>`
+pub struct SumMonoid<T>(PhantomData<T>);
+
+/// Multiplication monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a * b`
+/// - **identity**: `1`
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+/// - `GF<MOD>`
+pub struct ProductMonoid<T>(PhantomData<T>);
+
+/// Implements [`Monoid`] for [`SumMonoid<T>`] and [`ProductMonoid<T>`] for multiple numeric types.
+macro_rules! impl_sum_product_monoid {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Monoid for SumMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    0 as $t
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    a + b
+                }
+            }
+
+            impl Monoid for ProductMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    1 as $t
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    a * b
+                }
+            }
+        )*
+    };
+}
+impl_sum_product_monoid!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl<const MOD: u32> Monoid for SumMonoid<GF<MOD>> {
+    type S = GF<MOD>;
+
+    #[inline]
+    fn identity() -> Self::S {
+        GF::zero()
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        a + b
+    }
+}
+
+impl<const MOD: u32> Monoid for ProductMonoid<GF<MOD>> {
+    type S = GF<MOD>;
+
+    #[inline]
+    fn identity() -> Self::S {
+        GF::one()
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        a * b
+    }
+}
+
+impl<T> Commutative for SumMonoid<T> {}
+impl<T> Commutative for ProductMonoid<T> {}
+
+/// Minimum monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `min(a, b)`
+/// - **identity**: The maximum value of `T` (`T::MAX`, or `+∞` for floating point types)
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+pub struct MinMonoid<T>(PhantomData<T>);
+
+/// Maximum monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `max(a, b)`
+/// - **identity**: The minimum value of `T` (`T::MIN`, or `-∞` for floating point types)
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+pub struct MaxMonoid<T>(PhantomData<T>);
+
+/// Implements [`Monoid`] for [`MinMonoid<T>`] and [`MaxMonoid<T>`] for multiple numeric types.
+macro_rules! impl_min_max_monoid {
+    ($($t:ty => $min:expr, $max:expr);* $(;)?) => {
+        $(
+            impl Monoid for MinMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    $max
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    if b < a { *b } else { *a }
+                }
+            }
+
+            impl Monoid for MaxMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    $min
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    if b > a { *b } else { *a }
+                }
+            }
+        )*
+    };
+}
+impl_min_max_monoid!(
+    i8 => i8::MIN, i8::MAX;
+    i16 => i16::MIN, i16::MAX;
+    i32 => i32::MIN, i32::MAX;
+    i64 => i64::MIN, i64::MAX;
+    i128 => i128::MIN, i128::MAX;
+    isize => isize::MIN, isize::MAX;
+    u8 => u8::MIN, u8::MAX;
+    u16 => u16::MIN, u16::MAX;
+    u32 => u32::MIN, u32::MAX;
+    u64 => u64::MIN, u64::MAX;
+    u128 => u128::MIN, u128::MAX;
+    usize => usize::MIN, usize::MAX;
+    f32 => f32::NEG_INFINITY, f32::INFINITY;
+    f64 => f64::NEG_INFINITY, f64::INFINITY;
+);
+
+impl<T> Commutative for MinMonoid<T> {}
+impl<T> Idempotent for MinMonoid<T> {}
+impl<T> Commutative for MaxMonoid<T> {}
+impl<T> Idempotent for MaxMonoid<T> {}
+
+/// Greatest common divisor monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `gcd(a, b)`
+/// - **identity**: `0`
+///
+/// # Supported Types
+///
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+pub struct GcdMonoid<T>(PhantomData<T>);
+
+/// Implements [`Monoid`] for [`GcdMonoid<T>`] for multiple unsigned integer types.
+macro_rules! impl_gcd_monoid {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Monoid for GcdMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    gcd(*a, *b)
+                }
+            }
+        )*
+    };
+}
+impl_gcd_monoid!(u8, u16, u32, u64, u128, usize);
+
+impl<T> Commutative for GcdMonoid<T> {}
+impl<T> Idempotent for GcdMonoid<T> {}
+
+/// Bitwise xor monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a ^ b`
+/// - **identity**: `0`
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+pub struct XorMonoid<T>(PhantomData<T>);
+
+/// Bitwise and monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a & b`
+/// - **identity**: `!0` (all bits set)
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+pub struct AndMonoid<T>(PhantomData<T>);
+
+/// Bitwise or monoid over type `T`.
+///
+/// - **set**: `T`
+/// - **operation**: `a | b`
+/// - **identity**: `0`
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+pub struct OrMonoid<T>(PhantomData<T>);
+
+/// Implements [`Monoid`] for [`XorMonoid<T>`], [`AndMonoid<T>`] and [`OrMonoid<T>`] for multiple
+/// integer types.
+macro_rules! impl_bit_monoid {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Monoid for XorMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    a ^ b
+                }
+            }
+
+            impl Monoid for AndMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    !0
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    a & b
+                }
+            }
+
+            impl Monoid for OrMonoid<$t> {
+                type S = $t;
+
+                #[inline]
+                fn identity() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    a | b
+                }
+            }
+        )*
+    };
+}
+impl_bit_monoid!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<T> Commutative for XorMonoid<T> {}
+impl<T> Commutative for AndMonoid<T> {}
+impl<T> Idempotent for AndMonoid<T> {}
+impl<T> Commutative for OrMonoid<T> {}
+impl<T> Idempotent for OrMonoid<T> {}
+
+/// Maximum subarray sum monoid over type `T`.
+///
+/// Represents a non-empty sequence `a` by the tuple
+/// `(sum, max_prefix, max_suffix, max_subarray)` of sums of its non-empty contiguous parts.
+/// A single element `x` is represented by `(x, x, x, x)`.
+///
+/// - **set**: `(T, T, T, T)`
+/// - **operation**: Concatenation of sequences.
+/// - **identity**: `(0, T::MIN, T::MIN, T::MIN)` representing the empty sequence.
+///
+/// # Supported Types
+///
+/// - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+pub struct MaxSubarraySumMonoid<T>(PhantomData<T>);
+
+/// Implements [`Monoid`] for [`MaxSubarraySumMonoid<T>`] for multiple signed integer types.
+macro_rules! impl_max_subarray_sum_monoid {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Monoid for MaxSubarraySumMonoid<$t> {
+                type S = ($t, $t, $t, $t);
+
+                #[inline]
+                fn identity() -> Self::S {
+                    (0, <$t>::MIN, <$t>::MIN, <$t>::MIN)
+                }
+
+                #[inline]
+                fn op(a: &Self::S, b: &Self::S) -> Self::S {
+                    (
+                        a.0 + b.0,
+                        a.1.max(a.0.saturating_add(b.1)),
+                        b.2.max(a.2.saturating_add(b.0)),
+                        a.3.max(b.3).max(a.2.saturating_add(b.1)),
+                    )
+                }
+            }
+        )*
+    };
+}
+impl_max_subarray_sum_monoid!(i8, i16, i32, i64, i128, isize);
+
+/// Monoid that keeps the first present value.
+///
+/// - **set**: `Option<T>`
+/// - **operation**: `a` if `a` is `Some`, otherwise `b`.
+/// - **identity**: `None`
+pub struct FirstOfMonoid<T>(PhantomData<T>);
+
+impl<T: Clone> Monoid for FirstOfMonoid<T> {
+    type S = Option<T>;
+
+    #[inline]
+    fn identity() -> Self::S {
+        None
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        a.as_ref().or(b.as_ref()).cloned()
+    }
+}
+
+impl<T> Idempotent for FirstOfMonoid<T> {}
+
+/// Monoid that keeps the last present value.
+///
+/// - **set**: `Option<T>`
+/// - **operation**: `b` if `b` is `Some`, otherwise `a`.
+/// - **identity**: `None`
+pub struct LastOfMonoid<T>(PhantomData<T>);
+
+impl<T: Clone> Monoid for LastOfMonoid<T> {
+    type S = Option<T>;
+
+    #[inline]
+    fn identity() -> Self::S {
+        None
+    }
+
+    #[inline]
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        b.as_ref().or(a.as_ref()).cloned()
+    }
+}
+
+impl<T> Idempotent for LastOfMonoid<T> {}
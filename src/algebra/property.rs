@@ -0,0 +1,28 @@
+//! Algebraic properties
+//!
+//! Marker traits for additional laws of a binary operation.
+//!
+//! These traits have no methods. Implementing one of them promises that the operation of the
+//! structure (e.g. [`Monoid::op`](super::monoid::Monoid::op) or
+//! [`Group::op`](super::group::Group::op)) satisfies the law, so that data structures and
+//! algorithms relying on it can require it as a bound.
+//!
+//! # Available traits
+//!
+//! - [`Commutative`]: `a * b = b * a`
+//! - [`Idempotent`]: `a * a = a`
+
+/// A marker trait for a *commutative* operation.
+///
+/// The operation `*` must satisfy `a * b = b * a` for all `a, b`.
+///
+/// For example, a fold over a multiset or a range update on a Fenwick tree is well-defined only
+/// for commutative operations.
+pub trait Commutative {}
+
+/// A marker trait for an *idempotent* operation.
+///
+/// The operation `*` must satisfy `a * a = a` for all `a`.
+///
+/// For example, a sparse table may fold overlapping ranges only for idempotent operations.
+pub trait Idempotent {}
@@ -0,0 +1,32 @@
+//! Semigroup
+//!
+//! This trait represents **semigroup**.
+//!
+//! Semigroup is a set equipped with associative binary operation, which may have no identity
+//! element.
+//!
+//! # Definition
+//!
+//! A set `S` equipped with a binary operation `S x S -> S`, which we will denote `*`, is a
+//! semigroup if it satisfies the following axiom.
+//!
+//! - **associativity**: for all `a, b, c` in `S`, the equation `(a * b) * c == a * (b * c)` holds.
+//!
+//! A semigroup can be turned into a monoid by adjoining an identity element, see
+//! [`OptionMonoid`](super::monoid_combinator::OptionMonoid).
+
+/// A trait representating a *semigroup*.
+///
+/// A semigroup consists of:
+/// - A set `S` (the associated type [`Self::S`])
+/// - An associative binary operation ([`Self::op`])
+pub trait Semigroup {
+    /// The underlying set of the semigroup.
+    type S: Clone;
+
+    /// Returns the result of binary operation on `a` and `b`.
+    ///
+    /// This operation must be associative:
+    /// `op(&op(&a, &b), &c) == op(&a, &op(&b, &c))` for all `a, b, c` in `S`.
+    fn op(a: &Self::S, b: &Self::S) -> Self::S;
+}
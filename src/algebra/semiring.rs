@@ -0,0 +1,98 @@
+//! Semiring, Ring and Field
+//!
+//! These traits represent **semiring**, **ring** and **field**.
+//!
+//! # Definition
+//!
+//! A set `R` equipped with two binary operations `+` (addition) and `*` (multiplication) is a
+//! semiring if it satisfies the following axioms.
+//!
+//! - `(R, +, 0)` is a commutative monoid.
+//! - `(R, *, 1)` is a monoid.
+//! - **distributivity**: `a * (b + c) = a * b + a * c` and `(a + b) * c = a * c + b * c` for all
+//!   `a, b, c` in `R`.
+//! - **annihilation**: `0 * a = a * 0 = 0` for all `a` in `R`.
+//!
+//! A semiring is a **ring** if `(R, +, 0)` is a group, i.e. every `a` has an additive inverse `-a`.
+//!
+//! A commutative ring is a **field** if `0 != 1` and every `a != 0` has a multiplicative inverse
+//! `a^(-1)`.
+//!
+//! Algorithms written over [`Semiring`] work for numbers as well as for e.g. the tropical
+//! semiring `(min, +)`, where matrix multiplication computes shortest paths.
+
+/// A trait representating a *semiring*.
+///
+/// A semiring consists of:
+/// - A set `R` (the associated type [`Self::S`])
+/// - An associative and commutative addition ([`Self::add`]) with identity ([`Self::zero`])
+/// - An associative multiplication ([`Self::mul`]) with identity ([`Self::one`])
+pub trait Semiring {
+    /// The underlying set of the semiring.
+    type S: Clone;
+
+    /// Returns the additive identity `0`.
+    ///
+    /// It satisfies `add(&a, &zero()) = a` and `mul(&a, &zero()) = mul(&zero(), &a) = zero()`.
+    fn zero() -> Self::S;
+
+    /// Returns the multiplicative identity `1`.
+    ///
+    /// It satisfies `mul(&a, &one()) = mul(&one(), &a) = a`.
+    fn one() -> Self::S;
+
+    /// Returns `a + b`.
+    ///
+    /// This operation must be associative and commutative.
+    fn add(a: &Self::S, b: &Self::S) -> Self::S;
+
+    /// Returns `a * b`.
+    ///
+    /// This operation must be associative and distribute over [`Self::add`].
+    fn mul(a: &Self::S, b: &Self::S) -> Self::S;
+}
+
+/// A trait representating a *ring*.
+///
+/// A ring is a [`Semiring`] with additive inverses ([`Self::neg`]).
+pub trait Ring: Semiring {
+    /// Returns the additive inverse `-a`.
+    ///
+    /// It satisfies `add(&a, &neg(&a)) = zero()`.
+    fn neg(a: &Self::S) -> Self::S;
+
+    /// Returns `a - b`.
+    ///
+    /// ## Default Implementation
+    ///
+    /// The default implementation computes `add(&a, &neg(&b))`.
+    #[inline]
+    fn sub(a: &Self::S, b: &Self::S) -> Self::S {
+        Self::add(a, &Self::neg(b))
+    }
+}
+
+/// A trait representating a *field*.
+///
+/// A field is a commutative [`Ring`] with multiplicative inverses of non-zero elements
+/// ([`Self::inv`]).
+pub trait Field: Ring {
+    /// Returns the multiplicative inverse `a^(-1)`.
+    ///
+    /// It satisfies `mul(&a, &inv(&a)) = one()` for `a != zero()`.
+    ///
+    /// ## Panics
+    ///
+    /// May panic if `a == zero()`.
+    fn inv(a: &Self::S) -> Self::S;
+
+    /// Returns `a / b`.
+    ///
+    /// ## Default Implementation
+    ///
+    /// The default implementation computes `mul(&a, &inv(&b))`.
+    #[inline]
+    fn div(a: &Self::S, b: &Self::S) -> Self::S {
+        Self::mul(a, &Self::inv(b))
+    }
+}
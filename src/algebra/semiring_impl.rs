@@ -0,0 +1,399 @@
+//! Standard implementations of semirings.
+//!
+//! This module provides commonly used semiring implementations.
+//!
+//! # Available semirings
+//!
+//! - Numeric types: `+` and `*` as usual, implemented on the type itself (`S = Self`).
+//!   - `GF<MOD>`, `GF64<MOD>`, `f32`, `f64`: [`Field`]
+//!   - Integer types: [`Ring`] with wrapping arithmetic (`ℤ/2^k ℤ`)
+//! - `bool`: Boolean semiring with `or` as addition and `and` as multiplication.
+//! - [`MinPlus`]: Tropical semiring with `min` as addition and `+` as multiplication.
+//! - [`MaxPlus`]: Tropical semiring with `max` as addition and `+` as multiplication.
+
+use super::semiring::{Field, Ring, Semiring};
+use crate::math::gf::GF;
+use crate::math::gf64::GF64;
+
+use std::marker::PhantomData;
+
+impl<const MOD: u32> Semiring for GF<MOD> {
+    type S = GF<MOD>;
+
+    #[inline]
+    fn zero() -> Self::S {
+        GF::zero()
+    }
+
+    #[inline]
+    fn one() -> Self::S {
+        GF::one()
+    }
+
+    #[inline]
+    fn add(a: &Self::S, b: &Self::S) -> Self::S {
+        a + b
+    }
+
+    #[inline]
+    fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+        a * b
+    }
+}
+
+impl<const MOD: u32> Ring for GF<MOD> {
+    #[inline]
+    fn neg(a: &Self::S) -> Self::S {
+        -*a
+    }
+
+    #[inline]
+    fn sub(a: &Self::S, b: &Self::S) -> Self::S {
+        a - b
+    }
+}
+
+impl<const MOD: u32> Field for GF<MOD> {
+    #[inline]
+    fn inv(a: &Self::S) -> Self::S {
+        GF::inv(a)
+    }
+
+    #[inline]
+    fn div(a: &Self::S, b: &Self::S) -> Self::S {
+        a / b
+    }
+}
+
+impl<const MOD: u64> Semiring for GF64<MOD> {
+    type S = GF64<MOD>;
+
+    #[inline]
+    fn zero() -> Self::S {
+        GF64::zero()
+    }
+
+    #[inline]
+    fn one() -> Self::S {
+        GF64::one()
+    }
+
+    #[inline]
+    fn add(a: &Self::S, b: &Self::S) -> Self::S {
+        a + b
+    }
+
+    #[inline]
+    fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+        a * b
+    }
+}
+
+impl<const MOD: u64> Ring for GF64<MOD> {
+    #[inline]
+    fn neg(a: &Self::S) -> Self::S {
+        -*a
+    }
+
+    #[inline]
+    fn sub(a: &Self::S, b: &Self::S) -> Self::S {
+        a - b
+    }
+}
+
+impl<const MOD: u64> Field for GF64<MOD> {
+    #[inline]
+    fn inv(a: &Self::S) -> Self::S {
+        GF64::inv(a)
+    }
+
+    #[inline]
+    fn div(a: &Self::S, b: &Self::S) -> Self::S {
+        a / b
+    }
+}
+
+/// Implements [`Semiring`] and [`Ring`] for multiple integer types with wrapping arithmetic.
+macro_rules! impl_integer_ring {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Semiring for $t {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    1
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    a.wrapping_add(*b)
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    a.wrapping_mul(*b)
+                }
+            }
+
+            impl Ring for $t {
+                #[inline]
+                fn neg(a: &Self::S) -> Self::S {
+                    a.wrapping_neg()
+                }
+
+                #[inline]
+                fn sub(a: &Self::S, b: &Self::S) -> Self::S {
+                    a.wrapping_sub(*b)
+                }
+            }
+        )*
+    };
+}
+impl_integer_ring!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Implements [`Semiring`], [`Ring`] and [`Field`] for floating point types.
+macro_rules! impl_float_field {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Semiring for $t {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    0.0
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    1.0
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    a + b
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    a * b
+                }
+            }
+
+            impl Ring for $t {
+                #[inline]
+                fn neg(a: &Self::S) -> Self::S {
+                    -a
+                }
+
+                #[inline]
+                fn sub(a: &Self::S, b: &Self::S) -> Self::S {
+                    a - b
+                }
+            }
+
+            impl Field for $t {
+                #[inline]
+                fn inv(a: &Self::S) -> Self::S {
+                    a.recip()
+                }
+
+                #[inline]
+                fn div(a: &Self::S, b: &Self::S) -> Self::S {
+                    a / b
+                }
+            }
+        )*
+    };
+}
+impl_float_field!(f32, f64);
+
+impl Semiring for bool {
+    type S = bool;
+
+    #[inline]
+    fn zero() -> Self::S {
+        false
+    }
+
+    #[inline]
+    fn one() -> Self::S {
+        true
+    }
+
+    #[inline]
+    fn add(a: &Self::S, b: &Self::S) -> Self::S {
+        *a || *b
+    }
+
+    #[inline]
+    fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+        *a && *b
+    }
+}
+
+/// Tropical `(min, +)` semiring over type `T`.
+///
+/// - **set**: `T ∪ {+∞}`, where `+∞` is represented by `T::MAX` (or `T::INFINITY`).
+/// - **addition**: `min(a, b)` with identity `+∞`.
+/// - **multiplication**: `a + b` with identity `0`, where `+∞` absorbs.
+///
+/// The `k`-th power of an adjacency matrix over this semiring gives shortest walks with exactly
+/// `k` edges.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `u32`, `u64`, `u128`, `usize`
+/// - `f32`, `f64`
+pub struct MinPlus<T>(PhantomData<T>);
+
+/// Tropical `(max, +)` semiring over type `T`.
+///
+/// - **set**: `T ∪ {-∞}`, where `-∞` is represented by `T::MIN` (or `T::NEG_INFINITY`).
+/// - **addition**: `max(a, b)` with identity `-∞`.
+/// - **multiplication**: `a + b` with identity `0`, where `-∞` absorbs.
+///
+/// The `k`-th power of an adjacency matrix over this semiring gives longest walks with exactly
+/// `k` edges.
+///
+/// # Supported Types
+///
+/// - `i32`, `i64`, `i128`, `isize`
+/// - `f32`, `f64`
+pub struct MaxPlus<T>(PhantomData<T>);
+
+/// Implements [`Semiring`] for [`MinPlus<T>`] for multiple integer types.
+macro_rules! impl_min_plus {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Semiring for MinPlus<$t> {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    <$t>::MAX
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.min(b)
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    if *a == <$t>::MAX || *b == <$t>::MAX {
+                        <$t>::MAX
+                    } else {
+                        a + b
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_min_plus!(i32, i64, i128, isize, u32, u64, u128, usize);
+
+/// Implements [`Semiring`] for [`MaxPlus<T>`] for multiple signed integer types.
+macro_rules! impl_max_plus {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Semiring for MaxPlus<$t> {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    <$t>::MIN
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    0
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    *a.max(b)
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    if *a == <$t>::MIN || *b == <$t>::MIN {
+                        <$t>::MIN
+                    } else {
+                        a + b
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_max_plus!(i32, i64, i128, isize);
+
+/// Implements [`Semiring`] for [`MinPlus<T>`] and [`MaxPlus<T>`] for floating point types.
+macro_rules! impl_tropical_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Semiring for MinPlus<$t> {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    <$t>::INFINITY
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    0.0
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    a.min(*b)
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    a + b
+                }
+            }
+
+            impl Semiring for MaxPlus<$t> {
+                type S = $t;
+
+                #[inline]
+                fn zero() -> Self::S {
+                    <$t>::NEG_INFINITY
+                }
+
+                #[inline]
+                fn one() -> Self::S {
+                    0.0
+                }
+
+                #[inline]
+                fn add(a: &Self::S, b: &Self::S) -> Self::S {
+                    a.max(*b)
+                }
+
+                #[inline]
+                fn mul(a: &Self::S, b: &Self::S) -> Self::S {
+                    a + b
+                }
+            }
+        )*
+    };
+}
+impl_tropical_float!(f32, f64);
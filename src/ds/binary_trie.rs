@@ -0,0 +1,342 @@
+//! Binary Trie
+//!
+//! A data structure that maintains a multiset of non-negative integers as a trie over their binary
+//! representations (most significant bit first).
+//!
+//! It supports the following operations:
+//!
+//! - **insert / erase**: Add or remove an element.
+//! - **xor_all**: Replace every element `e` with `e ^ x` lazily.
+//! - **min_xor / max_xor**: Compute `min e ^ x` or `max e ^ x` over all elements `e`.
+//! - **kth**: Find the `k`-th smallest element.
+//! - **count_less_than_xor**: Count elements `e` with `e ^ x < k`.
+
+/// A *Binary Trie* over `u64` keys with multiset counts.
+///
+/// Every key must be smaller than `2^bits`, where `bits` is given at construction time.
+///
+/// All operations run in `O(bits)` time.
+#[derive(Debug, Clone)]
+pub struct BinaryTrie {
+    /// The number of bits of each key.
+    bits: u32,
+
+    /// Children of each node. Index `0` is the root, and `0` as a child means no child.
+    next: Vec<[usize; 2]>,
+
+    /// The number of elements in the subtree rooted at each node.
+    count: Vec<usize>,
+
+    /// The lazy xor applied to every element.
+    lazy: u64,
+}
+
+impl BinaryTrie {
+    /// Creates a new empty binary trie for keys smaller than `2^bits`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `bits`: Number of bits of each key (must satisfy `bits <= 64`).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `bits > 64`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn new(bits: u32) -> Self {
+        assert!(bits <= 64, "bits must be at most 64 (got bits = {})", bits);
+        Self {
+            bits,
+            next: vec![[0; 2]],
+            count: vec![0],
+            lazy: 0,
+        }
+    }
+
+    /// Returns the number of elements, counting multiplicity.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.count[0]
+    }
+
+    /// Returns `true` if the trie contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.count[0] == 0
+    }
+
+    /// Inserts one copy of `x`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `x >= 2^bits`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn insert(&mut self, x: u64) {
+        self.insert_n(x, 1);
+    }
+
+    /// Inserts `k` copies of `x`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `x >= 2^bits`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn insert_n(&mut self, x: u64, k: usize) {
+        self.check_key(x);
+        let x = x ^ self.lazy;
+        let mut v = 0;
+        self.count[v] += k;
+        for b in (0..self.bits).rev() {
+            let c = (x >> b & 1) as usize;
+            if self.next[v][c] == 0 {
+                self.next[v][c] = self.next.len();
+                self.next.push([0; 2]);
+                self.count.push(0);
+            }
+            v = self.next[v][c];
+            self.count[v] += k;
+        }
+    }
+
+    /// Removes one copy of `x` if present.
+    ///
+    /// ## Returns
+    ///
+    /// - `true` if `x` was present and one copy was removed.
+    /// - `false` if `x` was not present.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn erase(&mut self, x: u64) -> bool {
+        self.erase_n(x, 1) == 1
+    }
+
+    /// Removes up to `k` copies of `x`.
+    ///
+    /// ## Returns
+    ///
+    /// The number of copies actually removed.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn erase_n(&mut self, x: u64, k: usize) -> usize {
+        let Some(leaf) = self.find(x) else {
+            return 0;
+        };
+        let k = k.min(self.count[leaf]);
+        let x = x ^ self.lazy;
+        let mut v = 0;
+        self.count[v] -= k;
+        for b in (0..self.bits).rev() {
+            v = self.next[v][(x >> b & 1) as usize];
+            self.count[v] -= k;
+        }
+        k
+    }
+
+    /// Returns the number of copies of `x`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn count(&self, x: u64) -> usize {
+        self.find(x).map_or(0, |v| self.count[v])
+    }
+
+    /// Replaces every element `e` with `e ^ x`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `x >= 2^bits`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn xor_all(&mut self, x: u64) {
+        self.check_key(x);
+        self.lazy ^= x;
+    }
+
+    /// Returns `min e ^ x` over all elements `e`.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(m)`: The minimum value of `e ^ x`.
+    /// - `None`: The trie is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn min_xor(&self, x: u64) -> Option<u64> {
+        self.min_element(x).map(|e| e ^ x)
+    }
+
+    /// Returns `max e ^ x` over all elements `e`.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(m)`: The maximum value of `e ^ x`.
+    /// - `None`: The trie is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn max_xor(&self, x: u64) -> Option<u64> {
+        self.min_element(x ^ self.mask()).map(|e| e ^ x)
+    }
+
+    /// Returns the minimum element.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn min(&self) -> Option<u64> {
+        self.min_element(0)
+    }
+
+    /// Returns the maximum element.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn max(&self) -> Option<u64> {
+        self.min_element(self.mask())
+    }
+
+    /// Returns the `k`-th smallest element (0-indexed, counting multiplicity).
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(e)`: The `k`-th smallest element.
+    /// - `None`: `k >= len()`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn kth(&self, mut k: usize) -> Option<u64> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for b in (0..self.bits).rev() {
+            let c = (self.lazy >> b & 1) as usize;
+            let left = self.size(self.next[v][c]);
+            if k < left {
+                v = self.next[v][c];
+            } else {
+                k -= left;
+                v = self.next[v][c ^ 1];
+                res |= 1 << b;
+            }
+        }
+        Some(res)
+    }
+
+    /// Returns the number of elements `e` (counting multiplicity) with `e ^ x < k`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn count_less_than_xor(&self, x: u64, k: u64) -> usize {
+        if self.bits < 64 && k >> self.bits != 0 {
+            return self.len();
+        }
+        let x = x ^ self.lazy;
+        let mut v = 0;
+        let mut res = 0;
+        for b in (0..self.bits).rev() {
+            let c = (x >> b & 1) as usize;
+            if k >> b & 1 == 1 {
+                res += self.size(self.next[v][c]);
+                v = self.next[v][c ^ 1];
+            } else {
+                v = self.next[v][c];
+            }
+            if v == 0 {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Returns the number of elements `e` (counting multiplicity) with `e < k`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(bits)`
+    pub fn count_less_than(&self, k: u64) -> usize {
+        self.count_less_than_xor(0, k)
+    }
+
+    /// Returns the element `e` minimizing `e ^ x`.
+    fn min_element(&self, x: u64) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let x = x ^ self.lazy;
+        let mut v = 0;
+        let mut res = 0;
+        for b in (0..self.bits).rev() {
+            let mut c = (x >> b & 1) as usize;
+            if self.size(self.next[v][c]) == 0 {
+                c ^= 1;
+            }
+            v = self.next[v][c];
+            res |= (c as u64) << b;
+        }
+        Some(res ^ self.lazy)
+    }
+
+    /// Returns the leaf node of `x` if it exists.
+    fn find(&self, x: u64) -> Option<usize> {
+        if self.bits < 64 && x >> self.bits != 0 {
+            return None;
+        }
+        let x = x ^ self.lazy;
+        let mut v = 0;
+        for b in (0..self.bits).rev() {
+            v = self.next[v][(x >> b & 1) as usize];
+            if v == 0 {
+                return None;
+            }
+        }
+        Some(v)
+    }
+
+    /// Returns the number of elements in the subtree rooted at `v`, or `0` if `v` is no node.
+    fn size(&self, v: usize) -> usize {
+        if v == 0 { 0 } else { self.count[v] }
+    }
+
+    /// Returns `2^bits - 1`.
+    fn mask(&self) -> u64 {
+        if self.bits == 64 {
+            !0
+        } else {
+            (1 << self.bits) - 1
+        }
+    }
+
+    /// Checks that `x` fits in `bits` bits.
+    fn check_key(&self, x: u64) {
+        debug_assert!(
+            x <= self.mask(),
+            "invalid key: {} must be smaller than 2^{}",
+            x,
+            self.bits
+        );
+    }
+}
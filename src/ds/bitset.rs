@@ -0,0 +1,569 @@
+//! Bitset
+//!
+//! A dynamically sized sequence of bits packed into `u64` words.
+//!
+//! It supports the following operations in `O(n / 64)` time:
+//!
+//! - **bitwise operations**: `&`, `|`, `^`, `!` between bitsets of the same length.
+//! - **shift**: `<<` moves every bit to a higher index, `>>` moves every bit to a lower index.
+//! - **range set / reset**: Set or reset all bits in a range.
+//! - **scan**: Count set bits, find the first set bit or the next set bit.
+//!
+//! This is the basis of bit-parallel algorithms such as subset-sum DP, Gaussian elimination on
+//! `GF(2)` and bit-parallel LCS.
+
+use std::ops::RangeBounds;
+
+/// Number of bits in a word.
+const W: usize = u64::BITS as usize;
+
+/// A *Bitset* with a length fixed at construction time.
+///
+/// Bit `i` is stored in bit `i % 64` of word `i / 64`.
+/// Bits at index `n` and above in the last word are always kept zero.
+///
+/// # Examples
+///
+/// Shifts and flips across word boundaries, checked against the same bits as `Vec<bool>`:
+///
+/// ```
+/// use math_optim::ds::bitset::Bitset;
+///
+/// let bits = |b: &Bitset| (0..b.len()).map(|i| b.get(i)).collect::<Vec<_>>();
+/// for n in [63, 64, 65, 130] {
+///     let a: Vec<bool> = (0..n).map(|i| (i * i + 3 * i) % 7 < 3).collect();
+///     for k in [0, 1, 31, 63, 64, 65, 127, 129, 130, 200] {
+///         let mut b = Bitset::from_bools(&a);
+///         b <<= k;
+///         let expected: Vec<bool> = (0..n).map(|i| i >= k && a[i - k]).collect();
+///         assert_eq!(bits(&b), expected);
+///         // Bits shifted beyond `n` are discarded, so `!b` has exactly the other bits.
+///         assert_eq!((!&b).count_ones(), n - b.count_ones());
+///
+///         let mut b = Bitset::from_bools(&a);
+///         b >>= k;
+///         let expected: Vec<bool> = (0..n).map(|i| i + k < n && a[i + k]).collect();
+///         assert_eq!(bits(&b), expected);
+///     }
+///
+///     let mut b = Bitset::from_bools(&a);
+///     b.range_flip(1..n - 1);
+///     let expected: Vec<bool> = (0..n).map(|i| a[i] ^ (1..n - 1).contains(&i)).collect();
+///     assert_eq!(bits(&b), expected);
+///     b.range_flip(..);
+///     let expected: Vec<bool> = expected.iter().map(|x| !x).collect();
+///     assert_eq!(bits(&b), expected);
+/// }
+///
+/// let mut b = Bitset::new(130);
+/// b.set(0);
+/// b.set(64);
+/// b.set(129);
+/// assert_eq!(b.find_first(), Some(0));
+/// assert_eq!(b.find_next(0), Some(64));
+/// assert_eq!(b.find_next(64), Some(129));
+/// assert_eq!(b.find_next(129), None);
+/// assert_eq!(b.find_next(usize::MAX), None);
+/// assert_eq!(b.ones().collect::<Vec<_>>(), [0, 64, 129]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Bitset {
+    /// The number of bits managed by this.
+    n: usize,
+
+    /// Internal word array of size `ceil(n / 64)`.
+    data: Box<[u64]>,
+}
+
+impl Bitset {
+    /// Creates a new bitset of length `n` with all bits reset.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n`: Number of bits (indexed `0..n`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            data: vec![0; n.div_ceil(W)].into_boxed_slice(),
+        }
+    }
+
+    /// Creates a new bitset from a slice of booleans.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn from_bools(a: &[bool]) -> Self {
+        let mut res = Self::new(a.len());
+        for (i, _) in a.iter().enumerate().filter(|(_, b)| **b) {
+            res.data[i / W] |= 1 << (i % W);
+        }
+        res
+    }
+
+    /// Returns the number of bits.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `true` if the bitset has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns the underlying words.
+    ///
+    /// Bit `i` is bit `i % 64` of `words()[i / 64]`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn words(&self) -> &[u64] {
+        &self.data
+    }
+
+    /// Returns `true` if bit `i` is set.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn get(&self, i: usize) -> bool {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        self.data[i / W] >> (i % W) & 1 == 1
+    }
+
+    /// Sets bit `i` to `1`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn set(&mut self, i: usize) {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        self.data[i / W] |= 1 << (i % W);
+    }
+
+    /// Resets bit `i` to `0`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn reset(&mut self, i: usize) {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        self.data[i / W] &= !(1 << (i % W));
+    }
+
+    /// Flips bit `i`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn flip(&mut self, i: usize) {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        self.data[i / W] ^= 1 << (i % W);
+    }
+
+    /// Sets all bits in a range to `1`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn range_set(&mut self, range: impl RangeBounds<usize>) {
+        let (l, r) = self.bounds(range);
+        self.range_update(l, r, |x, mask| *x |= mask);
+    }
+
+    /// Resets all bits in a range to `0`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn range_reset(&mut self, range: impl RangeBounds<usize>) {
+        let (l, r) = self.bounds(range);
+        self.range_update(l, r, |x, mask| *x &= !mask);
+    }
+
+    /// Flips all bits in a range.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn range_flip(&mut self, range: impl RangeBounds<usize>) {
+        let (l, r) = self.bounds(range);
+        self.range_update(l, r, |x, mask| *x ^= mask);
+    }
+
+    /// Sets all bits to `1`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn set_all(&mut self) {
+        self.data.fill(!0);
+        self.trim();
+    }
+
+    /// Resets all bits to `0`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn reset_all(&mut self) {
+        self.data.fill(0);
+    }
+
+    /// Returns the number of set bits.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if at least one bit is set.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|&x| x != 0)
+    }
+
+    /// Returns the smallest index of a set bit.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(i)`: The smallest `i` such that bit `i` is set.
+    /// - `None`: No bit is set.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn find_first(&self) -> Option<usize> {
+        self.find_from(0)
+    }
+
+    /// Returns the smallest index of a set bit strictly greater than `i`.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(j)`: The smallest `j > i` such that bit `j` is set.
+    /// - `None`: No such bit exists.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64)`
+    pub fn find_next(&self, i: usize) -> Option<usize> {
+        i.checked_add(1).and_then(|j| self.find_from(j))
+    }
+
+    /// Returns an iterator over the indices of set bits in ascending order.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n / 64 + k)` in total, where `k` is the number of set bits.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.data.iter().enumerate().flat_map(|(k, &x)| {
+            let mut x = x;
+            std::iter::from_fn(move || {
+                (x != 0).then(|| {
+                    let i = x.trailing_zeros() as usize;
+                    x &= x - 1;
+                    k * W + i
+                })
+            })
+        })
+    }
+
+    /// Returns the smallest index `j >= i` of a set bit.
+    fn find_from(&self, i: usize) -> Option<usize> {
+        if i >= self.n {
+            return None;
+        }
+        let mut k = i / W;
+        let mut x = self.data[k] & (!0 << (i % W));
+        loop {
+            if x != 0 {
+                return Some(k * W + x.trailing_zeros() as usize);
+            }
+            k += 1;
+            if k == self.data.len() {
+                return None;
+            }
+            x = self.data[k];
+        }
+    }
+
+    /// Converts `range` into `[l, r)`.
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+        let l = match range.start_bound() {
+            Unbounded => 0,
+            Included(x) => *x,
+            Excluded(x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            Unbounded => self.n,
+            Included(x) => x + 1,
+            Excluded(x) => *x,
+        };
+        debug_assert!(
+            l <= r,
+            "invalid range: start {} must be smaller than or equal to end {}",
+            l,
+            r
+        );
+        debug_assert!(
+            r <= self.n,
+            "invalid range: range end {} must be smaller than length {}",
+            r,
+            self.n
+        );
+        (l, r)
+    }
+
+    /// Applies `f(word, mask)` to every word intersecting `[l, r)`, where `mask` selects the bits
+    /// of the word inside the range.
+    fn range_update(&mut self, l: usize, r: usize, f: impl Fn(&mut u64, u64)) {
+        if l >= r {
+            return;
+        }
+        let (lk, rk) = (l / W, (r - 1) / W);
+        let lmask = !0 << (l % W);
+        let rmask = !0 >> (W - 1 - (r - 1) % W);
+        if lk == rk {
+            f(&mut self.data[lk], lmask & rmask);
+        } else {
+            f(&mut self.data[lk], lmask);
+            for x in &mut self.data[lk + 1..rk] {
+                f(x, !0);
+            }
+            f(&mut self.data[rk], rmask);
+        }
+    }
+
+    /// Clears the unused bits of the last word.
+    fn trim(&mut self) {
+        if self.n % W != 0 {
+            *self.data.last_mut().unwrap() &= !0 >> (W - self.n % W);
+        }
+    }
+}
+
+use std::fmt::{Debug, Formatter, Result};
+impl Debug for Bitset {
+    /// Formats bits in index order, bit `0` first.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for i in 0..self.n {
+            write!(f, "{}", self.get(i) as u8)?;
+        }
+        Ok(())
+    }
+}
+
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+macro_rules! bitset_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+            $op:tt,
+    )*) => {$(
+        impl $trait_assign<&Bitset> for Bitset {
+            fn $fn_assign(&mut self, rhs: &Bitset) {
+                debug_assert_eq!(
+                    self.n, rhs.n,
+                    "length mismatch: {} and {}",
+                    self.n, rhs.n
+                );
+                for (x, y) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    *x $op *y;
+                }
+            }
+        }
+        impl $trait_assign<Bitset> for Bitset {
+            fn $fn_assign(&mut self, rhs: Bitset) {
+                self.$fn_assign(&rhs);
+            }
+        }
+        impl $trait<&Bitset> for Bitset {
+            type Output = Bitset;
+            fn $fn(mut self, rhs: &Bitset) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+        impl $trait<Bitset> for Bitset {
+            type Output = Bitset;
+            fn $fn(mut self, rhs: Bitset) -> Self::Output {
+                self.$fn_assign(&rhs);
+                self
+            }
+        }
+        impl $trait<&Bitset> for &Bitset {
+            type Output = Bitset;
+            fn $fn(self, rhs: &Bitset) -> Self::Output {
+                self.clone().$fn(rhs)
+            }
+        }
+    )*};
+}
+
+bitset_ops! {
+    BitAnd, BitAndAssign, bitand, bitand_assign, &=,
+    BitOr, BitOrAssign, bitor, bitor_assign, |=,
+    BitXor, BitXorAssign, bitxor, bitxor_assign, ^=,
+}
+
+impl Not for Bitset {
+    type Output = Bitset;
+    fn not(mut self) -> Self::Output {
+        for x in self.data.iter_mut() {
+            *x = !*x;
+        }
+        self.trim();
+        self
+    }
+}
+impl Not for &Bitset {
+    type Output = Bitset;
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+impl ShlAssign<usize> for Bitset {
+    /// Moves bit `i` to bit `i + k`. Bits shifted beyond `n` are discarded.
+    fn shl_assign(&mut self, k: usize) {
+        let (q, r) = (k / W, k % W);
+        let m = self.data.len();
+        if q >= m {
+            self.data.fill(0);
+            return;
+        }
+        for i in (q..m).rev() {
+            let hi = self.data[i - q] << r;
+            let lo = if r > 0 && i > q {
+                self.data[i - q - 1] >> (W - r)
+            } else {
+                0
+            };
+            self.data[i] = hi | lo;
+        }
+        self.data[..q].fill(0);
+        self.trim();
+    }
+}
+impl ShrAssign<usize> for Bitset {
+    /// Moves bit `i` to bit `i - k`. Bits shifted below `0` are discarded.
+    fn shr_assign(&mut self, k: usize) {
+        let (q, r) = (k / W, k % W);
+        let m = self.data.len();
+        if q >= m {
+            self.data.fill(0);
+            return;
+        }
+        for i in 0..m - q {
+            let lo = self.data[i + q] >> r;
+            let hi = if r > 0 && i + q + 1 < m {
+                self.data[i + q + 1] << (W - r)
+            } else {
+                0
+            };
+            self.data[i] = hi | lo;
+        }
+        self.data[m - q..].fill(0);
+    }
+}
+
+macro_rules! bitset_shift_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl $trait<usize> for Bitset {
+            type Output = Bitset;
+            fn $fn(mut self, k: usize) -> Self::Output {
+                self.$fn_assign(k);
+                self
+            }
+        }
+        impl $trait<usize> for &Bitset {
+            type Output = Bitset;
+            fn $fn(self, k: usize) -> Self::Output {
+                self.clone().$fn(k)
+            }
+        }
+    )*};
+}
+
+bitset_shift_ops! {
+    Shl, ShlAssign, shl, shl_assign,
+    Shr, ShrAssign, shr, shr_assign,
+}
@@ -0,0 +1,104 @@
+//! Cartesian Tree
+//!
+//! A binary tree built from a sequence such that the in-order traversal gives back the sequence
+//! and every node is smaller than or equal to its descendants (heap order).
+//!
+//! # Definition
+//!
+//! For a sequence `a = (a0, a1, ..., a(n-1))`, the **cartesian tree** of `a` is defined
+//! recursively:
+//!
+//! - The root is the index `m` of the minimum of `a` (the leftmost one if there are ties).
+//! - The left subtree is the cartesian tree of `a[0..m]`.
+//! - The right subtree is the cartesian tree of `a[m + 1..n]`.
+//!
+//! The lowest common ancestor of `l` and `r` in this tree is the position of the minimum of
+//! `a[l..=r]`, which reduces range minimum queries to LCA queries and vice versa.
+
+use std::cmp::Ordering;
+
+/// A *Cartesian Tree* represented by parent and children arrays.
+///
+/// Missing nodes are represented by `!0` (`usize::MAX`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CartesianTree {
+    /// The root of the tree, or `!0` if the sequence is empty.
+    pub root: usize,
+
+    /// `parent[i]` is the parent of `i`, or `!0` if `i` is the root.
+    pub parent: Vec<usize>,
+
+    /// `left[i]` is the left child of `i`, or `!0` if it does not exist.
+    pub left: Vec<usize>,
+
+    /// `right[i]` is the right child of `i`, or `!0` if it does not exist.
+    pub right: Vec<usize>,
+}
+
+/// Builds the cartesian tree of `a` where the root is the minimum.
+///
+/// Among equal elements, the leftmost one becomes the ancestor.
+///
+/// ## Complexity
+///
+/// `O(n)`
+pub fn min_cartesian_tree<T: Ord>(a: &[T]) -> CartesianTree {
+    cartesian_tree_by(a, |x, y| x.cmp(y))
+}
+
+/// Builds the cartesian tree of `a` where the root is the maximum.
+///
+/// Among equal elements, the leftmost one becomes the ancestor.
+///
+/// ## Complexity
+///
+/// `O(n)`
+pub fn max_cartesian_tree<T: Ord>(a: &[T]) -> CartesianTree {
+    cartesian_tree_by(a, |x, y| y.cmp(x))
+}
+
+/// Builds the cartesian tree of `a` with respect to the comparator `compare`.
+///
+/// The root is the minimum with respect to `compare`, and among equal elements the leftmost one
+/// becomes the ancestor.
+///
+/// ## Parameters
+///
+/// - `a`: The sequence.
+/// - `compare`: A total order on `T`.
+///
+/// ## Complexity
+///
+/// `O(n)`
+pub fn cartesian_tree_by<T>(a: &[T], mut compare: impl FnMut(&T, &T) -> Ordering) -> CartesianTree {
+    let n = a.len();
+    let mut parent = vec![!0; n];
+    let mut left = vec![!0; n];
+    let mut right = vec![!0; n];
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut last = !0;
+        while let Some(&top) = stack.last() {
+            if compare(&a[top], &a[i]) != Ordering::Greater {
+                break;
+            }
+            last = top;
+            stack.pop();
+        }
+        if last != !0 {
+            parent[last] = i;
+            left[i] = last;
+        }
+        if let Some(&top) = stack.last() {
+            parent[i] = top;
+            right[top] = i;
+        }
+        stack.push(i);
+    }
+    CartesianTree {
+        root: stack.first().copied().unwrap_or(!0),
+        parent,
+        left,
+        right,
+    }
+}
@@ -1,4 +1,12 @@
+pub mod binary_trie;
+pub mod bitset;
+pub mod cartesian_tree;
 pub mod dsu;
 pub mod lazy_segtree;
+pub mod persistent_array;
+pub mod persistent_queue;
+pub mod persistent_stack;
 pub mod potential_dsu;
 pub mod segtree;
+pub mod sqrt_decomposition;
+pub mod static_rmq;
@@ -0,0 +1,173 @@
+//! Persistent Array
+//!
+//! A fixed-length array where every update creates a new version and leaves all older versions
+//! accessible and unchanged.
+//!
+//! It supports the following operations:
+//!
+//! - **get**: Read an element of a version.
+//! - **set**: Create a new version with one element replaced.
+//!
+//! Versions are represented by values of [`PersistentArray`], which share unchanged parts through
+//! reference counting (path copying), so cloning a version is `O(1)`.
+
+use std::rc::Rc;
+
+/// A node of the balanced binary tree over the indices.
+#[derive(Debug)]
+enum Node<T> {
+    Leaf(T),
+    Branch(Rc<Node<T>>, Rc<Node<T>>),
+}
+
+/// A *Persistent Array* implemented as a path-copying balanced binary tree.
+///
+/// The node for the index range `[l, r)` has children for `[l, m)` and `[m, r)` where
+/// `m = l + (r - l) / 2`.
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[derive(Debug)]
+pub struct PersistentArray<T> {
+    /// The number of elements.
+    n: usize,
+
+    /// The root node, or `None` if `n == 0`.
+    root: Option<Rc<Node<T>>>,
+}
+
+impl<T> Clone for PersistentArray<T> {
+    /// Returns the same version. This is `O(1)`.
+    fn clone(&self) -> Self {
+        Self {
+            n: self.n,
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<T: Clone> PersistentArray<T> {
+    /// Creates a new persistent array whose `n` elements are all `x`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn new(n: usize, x: T) -> Self {
+        Self::from_slice(&vec![x; n])
+    }
+
+    /// Creates a new persistent array from a slice.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn from_slice(a: &[T]) -> Self {
+        fn build<T: Clone>(a: &[T]) -> Rc<Node<T>> {
+            if a.len() == 1 {
+                Rc::new(Node::Leaf(a[0].clone()))
+            } else {
+                let (l, r) = a.split_at(a.len() / 2);
+                Rc::new(Node::Branch(build(l), build(r)))
+            }
+        }
+        Self {
+            n: a.len(),
+            root: (!a.is_empty()).then(|| build(a)),
+        }
+    }
+
+    /// Returns a new version whose element at index `i` is `x`.
+    ///
+    /// `self` is not modified.
+    ///
+    /// ## Parameters
+    ///
+    /// - `i`: Index to update (must satisfy `0 <= i < n`).
+    /// - `x`: The new value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    pub fn set(&self, i: usize, x: T) -> Self {
+        fn set<T>(node: &Rc<Node<T>>, n: usize, i: usize, x: T) -> Rc<Node<T>> {
+            match node.as_ref() {
+                Node::Leaf(_) => Rc::new(Node::Leaf(x)),
+                Node::Branch(l, r) => {
+                    let m = n / 2;
+                    if i < m {
+                        Rc::new(Node::Branch(set(l, m, i, x), r.clone()))
+                    } else {
+                        Rc::new(Node::Branch(l.clone(), set(r, n - m, i - m, x)))
+                    }
+                }
+            }
+        }
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        Self {
+            n: self.n,
+            root: Some(set(self.root.as_ref().unwrap(), self.n, i, x)),
+        }
+    }
+}
+
+impl<T> PersistentArray<T> {
+    /// Returns the reference of index `i` in this version.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    pub fn get(&self, mut i: usize) -> &T {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        let mut n = self.n;
+        let mut node = self.root.as_ref().unwrap();
+        loop {
+            match node.as_ref() {
+                Node::Leaf(x) => return x,
+                Node::Branch(l, r) => {
+                    let m = n / 2;
+                    if i < m {
+                        node = l;
+                        n = m;
+                    } else {
+                        node = r;
+                        n -= m;
+                        i -= m;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+}
@@ -0,0 +1,173 @@
+//! Persistent Queue
+//!
+//! A queue where every update creates a new version and leaves all older versions accessible and
+//! unchanged.
+//!
+//! It supports the following operations in `O(log n)` time:
+//!
+//! - **push**: Create a new version with an element added at the back.
+//! - **pop**: Create a new version with the front element removed.
+//! - **front**: Read the front element.
+//!
+//! # Method
+//!
+//! All pushed elements form a tree where the parent of a node is the element pushed just before
+//! it. A version is the pair of its back node and its length, and the front node is the ancestor of
+//! the back node at distance `len - 1`, found by binary lifting.
+
+use std::rc::Rc;
+
+/// A node of the tree of pushed elements.
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+
+    /// `jump[k]` is the ancestor at distance `2^k`.
+    jump: Vec<Rc<Node<T>>>,
+}
+
+/// A *Persistent Queue* implemented on a tree with binary lifting.
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[derive(Debug)]
+pub struct PersistentQueue<T> {
+    /// The back node, or `None` if the queue is empty.
+    back: Option<Rc<Node<T>>>,
+
+    /// The number of elements.
+    len: usize,
+}
+
+impl<T> Clone for PersistentQueue<T> {
+    /// Returns the same version. This is `O(1)`.
+    fn clone(&self) -> Self {
+        Self {
+            back: self.back.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PersistentQueue<T> {
+    /// Creates a new empty persistent queue.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn new() -> Self {
+        Self { back: None, len: 0 }
+    }
+
+    /// Returns a new version with `x` pushed at the back.
+    ///
+    /// `self` is not modified.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    pub fn push(&self, x: T) -> Self {
+        let mut jump: Vec<Rc<Node<T>>> = vec![];
+        if self.len > 0 {
+            jump.push(self.back.clone().unwrap());
+            while jump.len() < usize::BITS as usize {
+                match jump.last().unwrap().jump.get(jump.len() - 1) {
+                    Some(next) => jump.push(next.clone()),
+                    None => break,
+                }
+            }
+        }
+        Self {
+            back: Some(Rc::new(Node { value: x, jump })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns a new version with the front element removed.
+    ///
+    /// `self` is not modified.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(q)`: The version without the front element.
+    /// - `None`: The queue is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn pop(&self) -> Option<Self> {
+        (self.len > 0).then(|| Self {
+            back: if self.len == 1 {
+                None
+            } else {
+                self.back.clone()
+            },
+            len: self.len - 1,
+        })
+    }
+
+    /// Returns the reference of the front element, or `None` if the queue is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    pub fn front(&self) -> Option<&T> {
+        let mut node = self.back.as_ref()?;
+        let mut d = self.len - 1;
+        while d > 0 {
+            let k = d.trailing_zeros() as usize;
+            node = &node.jump[k];
+            d &= d - 1;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns the reference of the back element, or `None` if the queue is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn back(&self) -> Option<&T> {
+        self.back.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Drop for PersistentQueue<T> {
+    /// Releases uniquely owned nodes iteratively to avoid deep recursion on long queues.
+    fn drop(&mut self) {
+        let mut back = self.back.take();
+        while let Some(node) = back {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => {
+                    back = if node.jump.is_empty() {
+                        None
+                    } else {
+                        Some(node.jump.swap_remove(0))
+                    };
+                }
+                Err(_) => break,
+            }
+        }
+    }
+}
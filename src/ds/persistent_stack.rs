@@ -0,0 +1,141 @@
+//! Persistent Stack
+//!
+//! A stack where every update creates a new version and leaves all older versions accessible and
+//! unchanged.
+//!
+//! It supports the following operations in `O(1)` time:
+//!
+//! - **push**: Create a new version with an element added on top.
+//! - **pop**: Create a new version with the top element removed.
+//! - **top**: Read the top element.
+//!
+//! Versions share their common suffix as an immutable singly linked list, so cloning a version is
+//! `O(1)`.
+
+use std::rc::Rc;
+
+/// A node of the linked list from the top to the bottom.
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// A *Persistent Stack* implemented as an immutable linked list.
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[derive(Debug)]
+pub struct PersistentStack<T> {
+    /// The top node, or `None` if the stack is empty.
+    head: Option<Rc<Node<T>>>,
+
+    /// The number of elements.
+    len: usize,
+}
+
+impl<T> Clone for PersistentStack<T> {
+    /// Returns the same version. This is `O(1)`.
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PersistentStack<T> {
+    /// Creates a new empty persistent stack.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Returns a new version with `x` pushed on top.
+    ///
+    /// `self` is not modified.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn push(&self, x: T) -> Self {
+        Self {
+            head: Some(Rc::new(Node {
+                value: x,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns a new version with the top element removed.
+    ///
+    /// `self` is not modified.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(s)`: The version without the top element.
+    /// - `None`: The stack is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn pop(&self) -> Option<Self> {
+        self.head.as_ref().map(|head| Self {
+            head: head.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    /// Returns the reference of the top element, or `None` if the stack is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn top(&self) -> Option<&T> {
+        self.head.as_ref().map(|head| &head.value)
+    }
+
+    /// Returns an iterator from the top to the bottom.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref())
+            .map(|node| &node.value)
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Drop for PersistentStack<T> {
+    /// Releases uniquely owned nodes iteratively to avoid deep recursion on long stacks.
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
@@ -26,7 +26,7 @@ use crate::algebra::group::Group;
 ///
 /// ## Type Parameters
 ///
-/// - `T`: A type implementing the [`Group`] trait. Group must not be commutative.
+/// - `T`: A type implementing the [`Group`] trait. Group need not be commutative.
 #[derive(Debug, Clone)]
 pub struct PotentialDSU<T: Group> {
     /// Parent array: negative values indicate root with set size (stored as `-size`),
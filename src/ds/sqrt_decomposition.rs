@@ -0,0 +1,410 @@
+//! Square Root Decomposition
+//!
+//! A framework for range updates and range queries that splits a sequence into blocks of about
+//! `√n` elements.
+//!
+//! It supports the following operations:
+//!
+//! - **range_update**: Apply an update to all elements in a range.
+//! - **range_query**: Compute a query over all elements in a range.
+//!
+//! For a range, every block fully covered is handled in bulk through its auxiliary data and lazy
+//! tag, and the at most two partially covered blocks are handled element by element and rebuilt.
+//!
+//! Unlike [`LazySegTree`](super::lazy_segtree::LazySegTree), the update does not have to be a
+//! homomorphism of a monoid, so queries such as "count elements `>= x` after range add" can be
+//! answered by keeping each block sorted.
+//!
+//! # Example
+//!
+//! Range add and counting the elements `>= x` in a range, keeping each block sorted:
+//!
+//! ```
+//! use math_optim::ds::sqrt_decomposition::{Bucket, SqrtDecomposition};
+//!
+//! struct AddCountGe;
+//!
+//! impl Bucket for AddCountGe {
+//!     type S = i64;
+//!     type Block = Vec<i64>; // The elements of the block in ascending order
+//!     type Lazy = i64; // The amount added to the whole block
+//!     type F = i64;
+//!     type Q = i64;
+//!     type R = usize;
+//!
+//!     fn identity_lazy() -> i64 {
+//!         0
+//!     }
+//!     fn build(a: &[i64]) -> Vec<i64> {
+//!         let mut sorted = a.to_vec();
+//!         sorted.sort_unstable();
+//!         sorted
+//!     }
+//!     fn push(a: &mut [i64], lazy: &i64) {
+//!         a.iter_mut().for_each(|x| *x += lazy);
+//!     }
+//!     fn update(x: &mut i64, f: &i64) {
+//!         *x += f;
+//!     }
+//!     fn update_block(_: &mut Vec<i64>, lazy: &mut i64, f: &i64) {
+//!         *lazy += f;
+//!     }
+//!     fn query(x: &i64, lazy: &i64, q: &i64) -> usize {
+//!         (x + lazy >= *q) as usize
+//!     }
+//!     fn query_block(block: &Vec<i64>, lazy: &i64, q: &i64) -> usize {
+//!         block.len() - block.partition_point(|x| x + lazy < *q)
+//!     }
+//!     fn identity_r() -> usize {
+//!         0
+//!     }
+//!     fn op_r(a: &usize, b: &usize) -> usize {
+//!         a + b
+//!     }
+//! }
+//!
+//! let mut sd = SqrtDecomposition::<AddCountGe>::new(vec![5, 1, 4, 2, 3, 6, 0, 7, 8]);
+//! sd.range_update(2..7, &3);
+//! // [5, 1, 7, 5, 6, 9, 3, 7, 8]
+//! assert_eq!(sd.range_query(.., &6), 5);
+//! assert_eq!(sd.range_query(1..4, &6), 1);
+//!
+//! // `AddCountGe` itself implements neither `Clone` nor `Debug`.
+//! let copy = sd.clone();
+//! assert_eq!(copy.range_query(.., &6), 5);
+//! assert!(format!("{:?}", copy).starts_with("SqrtDecomposition"));
+//! ```
+
+use std::fmt::{self, Debug, Formatter};
+use std::ops::RangeBounds;
+
+use crate::math::integer::isqrt;
+
+/// A trait describing the per-block behavior of [`SqrtDecomposition`].
+///
+/// A block consists of:
+/// - Its elements of type [`Self::S`]
+/// - Auxiliary data of type [`Self::Block`] built from the elements
+/// - A lazy tag of type [`Self::Lazy`] that is pending for all of its elements
+///
+/// The value of an element is the stored element with the lazy tag of its block applied.
+pub trait Bucket {
+    /// The element type.
+    type S;
+
+    /// The auxiliary data of a block.
+    type Block;
+
+    /// The lazy tag pending for a whole block.
+    type Lazy;
+
+    /// The update operand.
+    type F;
+
+    /// The query operand.
+    type Q;
+
+    /// The query result.
+    type R;
+
+    /// Returns the lazy tag that changes nothing.
+    fn identity_lazy() -> Self::Lazy;
+
+    /// Builds the auxiliary data of a block whose lazy tag is the identity.
+    fn build(a: &[Self::S]) -> Self::Block;
+
+    /// Applies the lazy tag to every element of a block.
+    fn push(a: &mut [Self::S], lazy: &Self::Lazy);
+
+    /// Applies the update to a single element.
+    fn update(x: &mut Self::S, f: &Self::F);
+
+    /// Applies the update to a whole block through its auxiliary data and lazy tag.
+    fn update_block(block: &mut Self::Block, lazy: &mut Self::Lazy, f: &Self::F);
+
+    /// Returns the query result for a single element under the lazy tag of its block.
+    fn query(x: &Self::S, lazy: &Self::Lazy, q: &Self::Q) -> Self::R;
+
+    /// Returns the query result for a whole block through its auxiliary data and lazy tag.
+    fn query_block(block: &Self::Block, lazy: &Self::Lazy, q: &Self::Q) -> Self::R;
+
+    /// Returns the identity of query results.
+    fn identity_r() -> Self::R;
+
+    /// Combines the query results of adjacent parts, `a` on the left and `b` on the right.
+    fn op_r(a: &Self::R, b: &Self::R) -> Self::R;
+}
+
+/// A *Square Root Decomposition* that supports range updates and range queries.
+///
+/// Let `B` be the block size. If [`Bucket::build`] and [`Bucket::push`] take `O(B)` time and the
+/// other methods take `O(1)` time, range operations take `O(B + n / B)` time, which is `O(√n)`
+/// with the default block size.
+///
+/// It implements `Clone` and `Debug` when the elements, the auxiliary data and the lazy tags do,
+/// whether or not `T` itself does.
+///
+/// # Type Parameters
+///
+/// - `T`: A type implementing the [`Bucket`] trait.
+pub struct SqrtDecomposition<T: Bucket> {
+    /// The number of elements.
+    n: usize,
+
+    /// The block size.
+    b: usize,
+
+    /// The elements, without the lazy tags of their blocks applied.
+    a: Vec<T::S>,
+
+    /// The auxiliary data of each block.
+    blocks: Vec<T::Block>,
+
+    /// The lazy tag of each block.
+    lazy: Vec<T::Lazy>,
+}
+
+impl<T: Bucket> Clone for SqrtDecomposition<T>
+where
+    T::S: Clone,
+    T::Block: Clone,
+    T::Lazy: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            n: self.n,
+            b: self.b,
+            a: self.a.clone(),
+            blocks: self.blocks.clone(),
+            lazy: self.lazy.clone(),
+        }
+    }
+}
+
+impl<T: Bucket> Debug for SqrtDecomposition<T>
+where
+    T::S: Debug,
+    T::Block: Debug,
+    T::Lazy: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqrtDecomposition")
+            .field("n", &self.n)
+            .field("b", &self.b)
+            .field("a", &self.a)
+            .field("blocks", &self.blocks)
+            .field("lazy", &self.lazy)
+            .finish()
+    }
+}
+
+impl<T: Bucket> SqrtDecomposition<T> {
+    /// Creates a new square root decomposition with block size `⌊√n⌋`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn new(a: Vec<T::S>) -> Self {
+        let b = (isqrt(a.len() as u64) as usize).max(1);
+        Self::with_block_size(a, b)
+    }
+
+    /// Creates a new square root decomposition with block size `b`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `b == 0`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn with_block_size(a: Vec<T::S>, b: usize) -> Self {
+        assert!(b > 0, "block size must be positive");
+        let n = a.len();
+        let blocks = a.chunks(b).map(T::build).collect::<Vec<_>>();
+        let lazy = std::iter::repeat_with(T::identity_lazy)
+            .take(blocks.len())
+            .collect();
+        Self {
+            n,
+            b,
+            a,
+            blocks,
+            lazy,
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns the reference of index `i` with the pending lazy tag applied.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(B)`
+    pub fn get(&mut self, i: usize) -> &T::S {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        let k = i / self.b;
+        self.push(k);
+        self.rebuild(k);
+        &self.a[i]
+    }
+
+    /// Updates the element at index `i` to the value `x`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(B)`
+    pub fn set(&mut self, i: usize, x: T::S) {
+        debug_assert!(
+            i < self.n,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.n
+        );
+        let k = i / self.b;
+        self.push(k);
+        self.a[i] = x;
+        self.rebuild(k);
+    }
+
+    /// Applies the update `f` to all elements in a range.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    /// - `f`: The update operand.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(B + n / B)`
+    pub fn range_update(&mut self, range: impl RangeBounds<usize>, f: &T::F) {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return;
+        }
+        let (lk, rk) = (l / self.b, (r - 1) / self.b);
+        if lk == rk {
+            self.partial_update(lk, l, r, f);
+            return;
+        }
+        self.partial_update(lk, l, (lk + 1) * self.b, f);
+        for k in lk + 1..rk {
+            T::update_block(&mut self.blocks[k], &mut self.lazy[k], f);
+        }
+        self.partial_update(rk, rk * self.b, r, f);
+    }
+
+    /// Computes the query `q` over all elements in a range.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    /// - `q`: The query operand.
+    ///
+    /// ## Returns
+    ///
+    /// The combination by [`Bucket::op_r`] of the results for all elements in the range.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(B + n / B)`
+    pub fn range_query(&self, range: impl RangeBounds<usize>, q: &T::Q) -> T::R {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return T::identity_r();
+        }
+        let (lk, rk) = (l / self.b, (r - 1) / self.b);
+        if lk == rk {
+            return self.partial_query(lk, l, r, q);
+        }
+        let mut res = self.partial_query(lk, l, (lk + 1) * self.b, q);
+        for k in lk + 1..rk {
+            res = T::op_r(&res, &T::query_block(&self.blocks[k], &self.lazy[k], q));
+        }
+        T::op_r(&res, &self.partial_query(rk, rk * self.b, r, q))
+    }
+
+    /// Applies `f` to the elements `[l, r)` of block `k` one by one and rebuilds the block.
+    fn partial_update(&mut self, k: usize, l: usize, r: usize, f: &T::F) {
+        self.push(k);
+        for x in &mut self.a[l..r] {
+            T::update(x, f);
+        }
+        self.rebuild(k);
+    }
+
+    /// Computes `q` over the elements `[l, r)` of block `k` one by one.
+    fn partial_query(&self, k: usize, l: usize, r: usize, q: &T::Q) -> T::R {
+        self.a[l..r].iter().fold(T::identity_r(), |acc, x| {
+            T::op_r(&acc, &T::query(x, &self.lazy[k], q))
+        })
+    }
+
+    /// Applies the lazy tag of block `k` to its elements and resets the tag.
+    ///
+    /// The block must be rebuilt afterwards.
+    fn push(&mut self, k: usize) {
+        let lazy = std::mem::replace(&mut self.lazy[k], T::identity_lazy());
+        let r = ((k + 1) * self.b).min(self.n);
+        T::push(&mut self.a[k * self.b..r], &lazy);
+    }
+
+    /// Rebuilds the auxiliary data of block `k`, whose lazy tag must be the identity.
+    fn rebuild(&mut self, k: usize) {
+        let r = ((k + 1) * self.b).min(self.n);
+        self.blocks[k] = T::build(&self.a[k * self.b..r]);
+    }
+
+    /// Converts `range` into `[l, r)`.
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+        let l = match range.start_bound() {
+            Unbounded => 0,
+            Included(x) => *x,
+            Excluded(x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            Unbounded => self.n,
+            Included(x) => x + 1,
+            Excluded(x) => *x,
+        };
+        debug_assert!(
+            l <= r,
+            "invalid range: start {} must be smaller than or equal to end {}",
+            l,
+            r
+        );
+        debug_assert!(
+            r <= self.n,
+            "invalid range: range end {} must be smaller than length {}",
+            r,
+            self.n
+        );
+        (l, r)
+    }
+}
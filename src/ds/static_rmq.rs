@@ -0,0 +1,189 @@
+//! Static Range Minimum Query
+//!
+//! A data structure that answers range minimum queries on a fixed sequence in `O(1)` time after
+//! `O(n)` preprocessing.
+//!
+//! # Method
+//!
+//! The sequence is split into blocks of `64` elements.
+//!
+//! - **Inside a block**: For each index `i`, the right spine of the cartesian tree of the block
+//!   prefix ending at `i` (the monotonic stack of [`cartesian_tree`](super::cartesian_tree)) is
+//!   stored as a 64-bit mask. The minimum of `a[l..=i]` is the lowest spine position `>= l`.
+//! - **Across blocks**: A sparse table over the block minima, of size `O(n / 64 * log n)`.
+
+use std::ops::RangeBounds;
+
+/// Number of elements in a block.
+const B: usize = u64::BITS as usize;
+
+/// A *Static RMQ* that answers range minimum queries in `O(1)` time.
+///
+/// Among equal minimum elements, the leftmost position is reported.
+/// For range maximum queries, use [`std::cmp::Reverse`] as the element type.
+///
+/// # Type Parameters
+///
+/// - `T`: A totally ordered element type.
+#[derive(Debug, Clone)]
+pub struct StaticRMQ<T: Ord> {
+    /// The sequence managed by this.
+    a: Box<[T]>,
+
+    /// `mask[i]` has bit `j` set if `i - i % 64 + j` is on the monotonic stack after pushing `i`.
+    mask: Box<[u64]>,
+
+    /// `table[k][b]` is the position of the minimum over blocks `b..b + 2^k`.
+    table: Vec<Box<[usize]>>,
+}
+
+impl<T: Ord> StaticRMQ<T> {
+    /// Creates a new static RMQ from a vector.
+    ///
+    /// ## Parameters
+    ///
+    /// - `a`: The sequence managed by this structure.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn new(a: Vec<T>) -> Self {
+        let n = a.len();
+        let mut mask = vec![0u64; n];
+        let mut block_min = Vec::with_capacity(n.div_ceil(B));
+        let mut stack: Vec<usize> = Vec::with_capacity(B);
+        for (k, block) in a.chunks(B).enumerate() {
+            let offset = k * B;
+            let mut m = 0u64;
+            stack.clear();
+            for (j, x) in block.iter().enumerate() {
+                while let Some(&top) = stack.last() {
+                    if block[top] <= *x {
+                        break;
+                    }
+                    m &= !(1 << top);
+                    stack.pop();
+                }
+                stack.push(j);
+                m |= 1 << j;
+                mask[offset + j] = m;
+            }
+            block_min.push(offset + stack[0]);
+        }
+
+        let mut table = vec![block_min.into_boxed_slice()];
+        let mut w = 1;
+        while 2 * w <= table[0].len() {
+            let prev = table.last().unwrap();
+            let next = (0..prev.len() - w)
+                .map(|b| Self::select(&a, prev[b], prev[b + w]))
+                .collect();
+            table.push(next);
+            w *= 2;
+        }
+
+        Self {
+            a: a.into_boxed_slice(),
+            mask: mask.into_boxed_slice(),
+            table,
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Returns `true` if the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.a.is_empty()
+    }
+
+    /// Returns the position of the minimum over a range.
+    ///
+    /// ## Parameters
+    ///
+    /// - `range`: The range of intervals. This must be `RangeBounds<usize>`.
+    ///
+    /// ## Returns
+    ///
+    /// If the sequence is `a` and `range` is `[l, r)`, returns the smallest `i` in `[l, r)` with
+    /// `a[i] = min(a[l], ..., a[r - 1])`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn argmin(&self, range: impl RangeBounds<usize>) -> usize {
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+        let l = match range.start_bound() {
+            Unbounded => 0,
+            Included(x) => *x,
+            Excluded(x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            Unbounded => self.a.len(),
+            Included(x) => x + 1,
+            Excluded(x) => *x,
+        };
+        debug_assert!(
+            l < r,
+            "invalid range: start {} must be smaller than end {}",
+            l,
+            r
+        );
+        debug_assert!(
+            r <= self.a.len(),
+            "invalid range: range end {} must be smaller than length {}",
+            r,
+            self.a.len()
+        );
+
+        let (lb, rb) = (l / B, (r - 1) / B);
+        if lb == rb {
+            return self.in_block(l, r - 1);
+        }
+        let mut res = self.in_block(l, lb * B + B - 1);
+        if lb + 1 < rb {
+            let k = (rb - lb - 1).ilog2() as usize;
+            res = Self::select(&self.a, res, self.table[k][lb + 1]);
+            res = Self::select(&self.a, res, self.table[k][rb - (1 << k)]);
+        }
+        Self::select(&self.a, res, self.in_block(rb * B, r - 1))
+    }
+
+    /// Returns the reference of the minimum over a range.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn min(&self, range: impl RangeBounds<usize>) -> &T {
+        &self.a[self.argmin(range)]
+    }
+
+    /// Returns the position of the minimum of `a[l..=r]` where `l` and `r` are in the same block.
+    #[inline]
+    fn in_block(&self, l: usize, r: usize) -> usize {
+        let m = self.mask[r] >> (l % B);
+        l + m.trailing_zeros() as usize
+    }
+
+    /// Returns `i` if `a[i] <= a[j]`, otherwise `j`. `i` must not be greater than `j`.
+    #[inline]
+    fn select(a: &[T], i: usize, j: usize) -> usize {
+        if a[j] < a[i] { j } else { i }
+    }
+}
@@ -0,0 +1,230 @@
+//! Binomial coefficients modulo arbitrary numbers
+//!
+//! Binomial coefficients `C(n, r) mod m` for huge `n` (up to `u64::MAX`) and any modulus `m`,
+//! where [`Comb`](super::gf_comb::Comb) would need `n!` itself and a prime modulus.
+//!
+//! # Available structures
+//!
+//! - [`Lucas`]: `C(n, r) mod p` for a small prime `p` (Lucas' theorem)
+//! - [`BinomPrimePower`]: `C(n, r) mod p^e` (generalized Lucas' theorem)
+//! - [`BinomMod`]: `C(n, r) mod m` for any `m`, combining prime powers by CRT
+//!
+//! # Available functions
+//!
+//! - [`binom_mod`]: Calculate `C(n, r) mod m` once
+
+use super::modint::{crt, inv_mod};
+use super::prime::factorize;
+
+/// A structure to compute `C(n, r) mod p` by Lucas' theorem.
+///
+/// `C(n, r) ≡ prod_i C(n_i, r_i) (mod p)` where `n_i`, `r_i` are the base-`p` digits.
+///
+/// # Requirements
+///
+/// **`p` must be prime.** The tables take `O(p)` memory.
+#[derive(Clone, Debug)]
+pub struct Lucas {
+    /// The prime modulus.
+    p: u64,
+
+    /// `fact[i] = i! mod p` for `0 <= i < p`.
+    fact: Vec<u64>,
+
+    /// `inv_fact[i] = (i!)^(-1) mod p` for `0 <= i < p`.
+    inv_fact: Vec<u64>,
+}
+
+impl Lucas {
+    /// Creates the tables for a prime `p`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(p)`
+    pub fn new(p: u32) -> Self {
+        let p = p as u64;
+        let mut fact = vec![1; p as usize];
+        for i in 1..p as usize {
+            fact[i] = fact[i - 1] * i as u64 % p;
+        }
+        let mut inv_fact = vec![1; p as usize];
+        inv_fact[p as usize - 1] = inv_mod(fact[p as usize - 1], p).unwrap();
+        for i in (1..p as usize).rev() {
+            inv_fact[i - 1] = inv_fact[i] * i as u64 % p;
+        }
+        Self { p, fact, inv_fact }
+    }
+
+    /// Returns `C(n, r) mod p` (`0` if `r > n`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log_p n)`
+    pub fn binom(&self, mut n: u64, mut r: u64) -> u64 {
+        let p = self.p;
+        let mut res = 1 % p;
+        while r > 0 {
+            let (ni, ri) = ((n % p) as usize, (r % p) as usize);
+            if ni < ri {
+                return 0;
+            }
+            res = res * self.fact[ni] % p * self.inv_fact[ri] % p * self.inv_fact[ni - ri] % p;
+            n /= p;
+            r /= p;
+        }
+        res
+    }
+}
+
+/// A structure to compute `C(n, r) mod p^e` by the generalized Lucas' theorem.
+///
+/// Writing `n! = p^(v(n)) F(n)` with `p ∤ F(n)`, `F(n) = (n!)_p F(n / p)` where `(n!)_p` is the
+/// product of the integers `<= n` coprime to `p`. Modulo `q = p^e`, `(n!)_p` is periodic up to
+/// the sign `(q!)_p = ±1`, so a table of size `q` suffices, and
+///
+/// `C(n, r) = p^(v(n) - v(r) - v(n-r)) F(n) / (F(r) F(n-r))`.
+///
+/// # Requirements
+///
+/// **`p` must be prime.** The table takes `O(p^e)` memory.
+#[derive(Clone, Debug)]
+pub struct BinomPrimePower {
+    /// The prime.
+    p: u64,
+
+    /// The exponent.
+    e: u32,
+
+    /// The modulus `p^e`.
+    q: u64,
+
+    /// `fact[i]` is the product of the integers in `1..=i` coprime to `p`, modulo `q`.
+    fact: Vec<u64>,
+}
+
+impl BinomPrimePower {
+    /// Creates the table for the prime power `p^e`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(p^e)`
+    pub fn new(p: u32, e: u32) -> Self {
+        let p = p as u64;
+        let q = p.pow(e);
+        let mut fact = vec![1 % q; q as usize];
+        for i in 1..q as usize {
+            fact[i] = if i as u64 % p == 0 {
+                fact[i - 1]
+            } else {
+                fact[i - 1] * i as u64 % q
+            };
+        }
+        Self { p, e, q, fact }
+    }
+
+    /// Returns the modulus `p^e`.
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.q
+    }
+
+    /// Returns `(v(n), F(n) mod q)` where `n! = p^(v(n)) F(n)`.
+    fn factorial(&self, mut n: u64) -> (u64, u64) {
+        let (p, q) = (self.p, self.q);
+        let mut v = 0;
+        let mut f = 1 % q;
+        // (q!)_p ≡ -1 (mod q) except for p = 2, e >= 3 (generalized Wilson's theorem).
+        let flip = !(p == 2 && self.e >= 3);
+        while n > 0 {
+            if flip && (n / q) % 2 == 1 {
+                f = (q - f) % q;
+            }
+            f = f * self.fact[(n % q) as usize] % q;
+            n /= p;
+            v += n;
+        }
+        (v, f)
+    }
+
+    /// Returns `C(n, r) mod p^e` (`0` if `r > n`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log_p n + e)`
+    pub fn binom(&self, n: u64, r: u64) -> u64 {
+        if r > n {
+            return 0;
+        }
+        let q = self.q;
+        let (vn, fn_) = self.factorial(n);
+        let (vr, fr) = self.factorial(r);
+        let (vnr, fnr) = self.factorial(n - r);
+        let v = vn - vr - vnr;
+        if v >= self.e as u64 {
+            return 0;
+        }
+        let den = inv_mod(fr * fnr % q, q).unwrap();
+        fn_ * den % q * self.p.pow(v as u32) % q
+    }
+}
+
+/// A structure to compute `C(n, r) mod m` for any modulus `m`.
+///
+/// `m` is factorized into prime powers, each handled by [`BinomPrimePower`], and the results are
+/// combined by the Chinese Remainder Theorem.
+#[derive(Clone, Debug)]
+pub struct BinomMod {
+    /// The modulus.
+    m: u64,
+
+    /// One structure per prime power dividing `m`.
+    parts: Vec<BinomPrimePower>,
+}
+
+impl BinomMod {
+    /// Creates the tables for the modulus `m` (satisfy `m >= 1`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(m)` in the worst case (the sum of the prime powers dividing `m`)
+    pub fn new(m: u32) -> Self {
+        debug_assert!(m >= 1, "modulus must be positive");
+        let factors = factorize(m as u64);
+        let parts = factors
+            .chunk_by(|a, b| a == b)
+            .map(|c| BinomPrimePower::new(c[0] as u32, c.len() as u32))
+            .collect();
+        Self { m: m as u64, parts }
+    }
+
+    /// Returns the modulus `m`.
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.m
+    }
+
+    /// Returns `C(n, r) mod m` (`0` if `r > n`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(k log n)` where `k` is the number of distinct prime factors of `m`
+    pub fn binom(&self, n: u64, r: u64) -> u64 {
+        let congruences: Vec<(u64, u64)> = self
+            .parts
+            .iter()
+            .map(|part| (part.binom(n, r), part.modulus()))
+            .collect();
+        crt(&congruences).unwrap().0 % self.m
+    }
+}
+
+/// Returns `C(n, r) mod m` (`0` if `r > n`).
+///
+/// Builds a [`BinomMod`] for this single query; reuse a [`BinomMod`] for many queries.
+///
+/// ## Complexity
+///
+/// `O(m)` in the worst case
+pub fn binom_mod(n: u64, r: u64, m: u32) -> u64 {
+    BinomMod::new(m).binom(n, r)
+}
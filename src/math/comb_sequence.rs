@@ -0,0 +1,269 @@
+//! Combinatorial sequences
+//!
+//! Tables of classical combinatorial numbers modulo `MOD`, computed with formal power series.
+//! `MOD` must be an NTT-friendly prime (e.g. `998244353`) larger than the table sizes.
+//!
+//! # Available functions
+//!
+//! - [`stirling_first_row`], [`stirling_first_column`]: Stirling numbers of the first kind
+//!   `s(n, k)` (signed)
+//! - [`stirling_second_row`], [`stirling_second_column`]: Stirling numbers of the second kind
+//!   `S(n, k)`
+//! - [`bell`]: Bell numbers `B_n`
+//! - [`partition`]: Partition numbers `p(n)`
+//! - [`bernoulli`]: Bernoulli numbers `B_n` (with `B_1 = -1/2`)
+//! - [`catalan`]: Catalan numbers `C_n`
+
+use super::conv::ntt::ntt_conv;
+use super::fps::Fps;
+use super::gf::GF;
+use super::gf_comb::Comb;
+
+/// Returns `[s(n, 0), s(n, 1), ..., s(n, n)]`, the signed Stirling numbers of the first kind.
+///
+/// ## Definition
+///
+/// `x (x-1) (x-2) ... (x-n+1) = sum_k s(n, k) x^k`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn stirling_first_row<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(n as u32 + 1);
+    // The unsigned numbers are the coefficients of the rising factorial x (x+1) ... (x+n-1).
+    let mut row = rising_factorial(n, &comb);
+    for (k, x) in row.iter_mut().enumerate() {
+        if (n - k) % 2 == 1 {
+            *x = -*x;
+        }
+    }
+    row
+}
+
+/// Returns `[s(0, k), s(1, k), ..., s(n-1, k)]`, the signed Stirling numbers of the first kind.
+///
+/// ## Definition
+///
+/// `sum_i s(i, k) x^i / i! = log(1 + x)^k / k!`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn stirling_first_column<const MOD: u32>(k: usize, n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(n.max(k + 1) as u32);
+    // log(1 + x) = sum_(j>=1) (-1)^(j+1) x^j / j
+    let log: Fps<MOD> = (0..n)
+        .map(|j| match j {
+            0 => GF::zero(),
+            _ if j % 2 == 1 => inv(j, &comb),
+            _ => -inv(j, &comb),
+        })
+        .collect::<Vec<_>>()
+        .into();
+    from_egf(log.pow(k as u64, n).into_vec(), &comb)
+        .into_iter()
+        .map(|x| x * comb.inv_factorial(k as u32))
+        .collect()
+}
+
+/// Returns `[S(n, 0), S(n, 1), ..., S(n, n)]`, the Stirling numbers of the second kind.
+///
+/// ## Definition
+///
+/// `S(n, k)` is the number of partitions of an `n`-element set into `k` non-empty subsets:
+///
+/// `S(n, k) = sum_i (-1)^(k-i) i^n / (i! (k-i)!)`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn stirling_second_row<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(n as u32 + 1);
+    let a: Vec<GF<MOD>> = (0..=n as u32)
+        .map(|i| GF::new(i).pow(n as u32) * comb.inv_factorial(i))
+        .collect();
+    let b: Vec<GF<MOD>> = (0..=n as u32)
+        .map(|i| {
+            let x = comb.inv_factorial(i);
+            if i % 2 == 0 { x } else { -x }
+        })
+        .collect();
+    let mut row = ntt_conv(&a, &b);
+    row.truncate(n + 1);
+    row
+}
+
+/// Returns `[S(0, k), S(1, k), ..., S(n-1, k)]`, the Stirling numbers of the second kind.
+///
+/// ## Definition
+///
+/// `sum_i S(i, k) x^i / i! = (e^x - 1)^k / k!`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn stirling_second_column<const MOD: u32>(k: usize, n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(n.max(k + 1) as u32);
+    from_egf(exp_minus_one(n, &comb).pow(k as u64, n).into_vec(), &comb)
+        .into_iter()
+        .map(|x| x * comb.inv_factorial(k as u32))
+        .collect()
+}
+
+/// Returns `[B_0, B_1, ..., B_(n-1)]`, the Bell numbers.
+///
+/// ## Definition
+///
+/// `B_n` is the number of partitions of an `n`-element set:
+///
+/// `sum_n B_n x^n / n! = exp(e^x - 1)`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn bell<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(n.max(1) as u32);
+    from_egf(exp_minus_one(n, &comb).exp(n).into_vec(), &comb)
+}
+
+/// Returns `[p(0), p(1), ..., p(n-1)]`, the partition numbers.
+///
+/// ## Definition
+///
+/// `p(n)` is the number of ways to write `n` as a sum of positive integers ignoring order:
+///
+/// `sum_n p(n) x^n = 1 / prod_(i>=1) (1 - x^i) = 1 / sum_k (-1)^k x^(k(3k-1)/2)`
+///
+/// where the last sum runs over all integers `k` (Euler's pentagonal number theorem).
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn partition<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let mut euler = vec![GF::zero(); n.max(1)];
+    euler[0] = GF::one();
+    for k in 1.. {
+        let sign = if k % 2 == 0 { GF::one() } else { -GF::one() };
+        let p = k * (3 * k - 1) / 2;
+        if p >= n {
+            break;
+        }
+        euler[p] += sign;
+        if p + k < n {
+            euler[p + k] += sign;
+        }
+    }
+    Fps::new(euler).inv(n).into_vec()
+}
+
+/// Returns `[B_0, B_1, ..., B_(n-1)]`, the Bernoulli numbers (with `B_1 = -1/2`).
+///
+/// Returns an empty vector for `n = 0`.
+///
+/// ## Definition
+///
+/// `sum_n B_n x^n / n! = x / (e^x - 1)`
+///
+/// ## Complexity
+///
+/// `O(n log n)`
+pub fn bernoulli<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let comb = Comb::<MOD>::build(n as u32 + 1);
+    // (e^x - 1) / x = sum_i x^i / (i+1)!
+    let f: Fps<MOD> = (1..=n as u32)
+        .map(|i| comb.inv_factorial(i))
+        .collect::<Vec<_>>()
+        .into();
+    from_egf(f.inv(n).into_vec(), &comb)
+}
+
+/// Returns `[C_0, C_1, ..., C_(n-1)]`, the Catalan numbers.
+///
+/// ## Definition
+///
+/// `C_n = (2n)! / (n! (n+1)!)`
+///
+/// ## Complexity
+///
+/// `O(n)`
+pub fn catalan<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let comb = Comb::<MOD>::build(2 * n.max(1) as u32);
+    (0..n as u32)
+        .map(|i| comb.factorial(2 * i) * comb.inv_factorial(i) * comb.inv_factorial(i + 1))
+        .collect()
+}
+
+/// Returns `1 / j` for `1 <= j < comb.n`.
+fn inv<const MOD: u32>(j: usize, comb: &Comb<MOD>) -> GF<MOD> {
+    comb.factorial(j as u32 - 1) * comb.inv_factorial(j as u32)
+}
+
+/// Returns `e^x - 1 mod x^n`.
+fn exp_minus_one<const MOD: u32>(n: usize, comb: &Comb<MOD>) -> Fps<MOD> {
+    (0..n as u32)
+        .map(|i| match i {
+            0 => GF::zero(),
+            _ => comb.inv_factorial(i),
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Converts exponential generating function coefficients `a_i / i!` into `a_i`.
+fn from_egf<const MOD: u32>(mut f: Vec<GF<MOD>>, comb: &Comb<MOD>) -> Vec<GF<MOD>> {
+    for (i, x) in f.iter_mut().enumerate() {
+        *x *= comb.factorial(i as u32);
+    }
+    f
+}
+
+/// Returns the coefficients of `x (x+1) (x+2) ... (x+n-1)`.
+///
+/// Uses doubling: the product for `2m` is `f(x) f(x+m)` where `f` is the product for `m`.
+fn rising_factorial<const MOD: u32>(n: usize, comb: &Comb<MOD>) -> Vec<GF<MOD>> {
+    if n == 0 {
+        return vec![GF::one()];
+    }
+    let m = n / 2;
+    let f = rising_factorial(m, comb);
+    let g = taylor_shift(&f, GF::new(m as u32), comb);
+    let mut f = ntt_conv(&f, &g);
+    if n % 2 == 1 {
+        // Multiply by (x + n - 1).
+        let c = GF::new(n as u32 - 1);
+        f.push(GF::zero());
+        for i in (0..f.len()).rev() {
+            let lower = if i > 0 { f[i - 1] } else { GF::zero() };
+            f[i] = f[i] * c + lower;
+        }
+    }
+    f
+}
+
+/// Returns the coefficients of `f(x + c)`.
+///
+/// `[x^i] f(x + c) = (1 / i!) sum_j (f_j j!) (c^(j-i) / (j-i)!)`, a single convolution.
+fn taylor_shift<const MOD: u32>(f: &[GF<MOD>], c: GF<MOD>, comb: &Comb<MOD>) -> Vec<GF<MOD>> {
+    let m = f.len();
+    let a: Vec<GF<MOD>> = f
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(j, x)| x * comb.factorial(j as u32))
+        .collect();
+    let mut c_pow = GF::one();
+    let b: Vec<GF<MOD>> = (0..m as u32)
+        .map(|t| {
+            let x = c_pow * comb.inv_factorial(t);
+            c_pow *= c;
+            x
+        })
+        .collect();
+    let ab = ntt_conv(&a, &b);
+    (0..m)
+        .map(|i| ab[m - 1 - i] * comb.inv_factorial(i as u32))
+        .collect()
+}
@@ -0,0 +1,163 @@
+//! Convolution under arbitrary modulus
+//!
+//! Convolution for moduli which are not NTT-friendly (e.g. `10^9 + 7`) and for exact integer
+//! products.
+//!
+//! The sequences are convolved by [`ntt_conv`] under three NTT-friendly primes
+//! `M1 = 754974721`, `M2 = 167772161`, `M3 = 469762049`, and every coefficient is recovered
+//! modulo `M1 M2 M3 (≈ 5.9 * 10^25 ≈ 2^85.6)` by Garner's algorithm (Chinese Remainder Theorem).
+//! The result is exact as long as every true coefficient is smaller than `M1 M2 M3`.
+//!
+//! # Available functions
+//!
+//! - [`convolution_arbitrary_mod`]: Convolution in `ℤ/MOD ℤ` for any `MOD`
+//! - [`convolution_u64`]: Exact convolution of non-negative integers
+//! - [`convolution_i64`]: Exact convolution of signed integers
+
+use super::ntt::ntt_conv;
+use crate::math::gf::GF;
+
+/// The first NTT-friendly prime `45 * 2^24 + 1`.
+const M1: u32 = 754_974_721;
+
+/// The second NTT-friendly prime `5 * 2^25 + 1`.
+const M2: u32 = 167_772_161;
+
+/// The third NTT-friendly prime `7 * 2^26 + 1`.
+const M3: u32 = 469_762_049;
+
+/// `M1 M2 M3`, the modulus of the values recovered by [`convolution_crt`].
+const M123: u128 = M1 as u128 * M2 as u128 * M3 as u128;
+
+/// Computes the convolution of `lhs` and `rhs` in `ℤ/MOD ℤ` for any `MOD`.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i) mod MOD`
+///
+/// ## Requirements
+///
+/// - `lhs.len() + rhs.len() - 1 <= 2^24`
+/// - `min(lhs.len(), rhs.len()) * (MOD - 1)^2 < M1 M2 M3 ≈ 2^85.6`
+///   (always satisfied for `MOD < 2^31` within the length limit)
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_arbitrary_mod<const MOD: u32>(lhs: &[GF<MOD>], rhs: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    let lhs: Vec<u64> = lhs.iter().map(|x| x.value() as u64).collect();
+    let rhs: Vec<u64> = rhs.iter().map(|x| x.value() as u64).collect();
+    convolution_crt(&lhs, &rhs)
+        .into_iter()
+        .map(|x| GF::new((x % MOD as u128) as u32))
+        .collect()
+}
+
+/// Computes the exact convolution of non-negative integer sequences.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i)`
+///
+/// ## Requirements
+///
+/// - `lhs.len() + rhs.len() - 1 <= 2^24`
+/// - Every coefficient `ck` fits in `u64`.
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_u64(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    convolution_crt(lhs, rhs)
+        .into_iter()
+        .map(|x| x as u64)
+        .collect()
+}
+
+/// Computes the exact convolution of signed integer sequences.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i)`
+///
+/// ## Requirements
+///
+/// - `lhs.len() + rhs.len() - 1 <= 2^24`
+/// - Every coefficient `ck` fits in `i64`.
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_i64(lhs: &[i64], rhs: &[i64]) -> Vec<i64> {
+    // Represent negative values by their residues modulo `M1 M2 M3`.
+    let to_residue = |x: &i64| (*x as i128).rem_euclid(M123 as i128) as u128;
+    let lhs: Vec<u128> = lhs.iter().map(to_residue).collect();
+    let rhs: Vec<u128> = rhs.iter().map(to_residue).collect();
+    convolution_crt_residues(&lhs, &rhs)
+        .into_iter()
+        .map(|x| {
+            if x > M123 / 2 {
+                (x as i128 - M123 as i128) as i64
+            } else {
+                x as i64
+            }
+        })
+        .collect()
+}
+
+/// Returns the convolution of `lhs` and `rhs` modulo `M1 M2 M3`.
+fn convolution_crt(lhs: &[u64], rhs: &[u64]) -> Vec<u128> {
+    let lhs: Vec<u128> = lhs.iter().map(|&x| x as u128).collect();
+    let rhs: Vec<u128> = rhs.iter().map(|&x| x as u128).collect();
+    convolution_crt_residues(&lhs, &rhs)
+}
+
+/// Returns the convolution of `lhs` and `rhs` modulo `M1 M2 M3`, where the inputs are given as
+/// arbitrary `u128` values (only their residues modulo `M1`, `M2`, `M3` are used).
+fn convolution_crt_residues(lhs: &[u128], rhs: &[u128]) -> Vec<u128> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    debug_assert!(
+        lhs.len() + rhs.len() - 1 <= 1 << 24,
+        "invalid length: {} must not be greater than {}",
+        lhs.len() + rhs.len() - 1,
+        1 << 24
+    );
+
+    let c1 = convolution_under::<M1>(lhs, rhs);
+    let c2 = convolution_under::<M2>(lhs, rhs);
+    let c3 = convolution_under::<M3>(lhs, rhs);
+
+    // Garner's algorithm: x = x1 + M1 t2 + M1 M2 t3 with 0 <= t2 < M2, 0 <= t3 < M3.
+    let m1_inv_m2 = GF::<M2>::new(M1).inv();
+    let m12_inv_m3 = (GF::<M3>::new(M1) * GF::<M3>::new(M2)).inv();
+    let m12 = M1 as u128 * M2 as u128;
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((x1, x2), x3)| {
+            let t2 = (x2 - GF::<M2>::new(x1.value())) * m1_inv_m2;
+            let x12 = x1.value() as u128 + M1 as u128 * t2.value() as u128;
+            let t3 = (x3 - GF::<M3>::new((x12 % M3 as u128) as u32)) * m12_inv_m3;
+            x12 + m12 * t3.value() as u128
+        })
+        .collect()
+}
+
+/// Returns the convolution of `lhs` and `rhs` in `ℤ/MOD ℤ` by [`ntt_conv`].
+fn convolution_under<const MOD: u32>(lhs: &[u128], rhs: &[u128]) -> Vec<GF<MOD>> {
+    let reduce = |x: &u128| GF::<MOD>::new((x % MOD as u128) as u32);
+    let lhs: Vec<GF<MOD>> = lhs.iter().map(reduce).collect();
+    let rhs: Vec<GF<MOD>> = rhs.iter().map(reduce).collect();
+    ntt_conv(&lhs, &rhs)
+}
@@ -0,0 +1,407 @@
+//! Fast Fourier Transform (FFT)
+//!
+//! Functions for floating-point FFT and convolution.
+//!
+//! Unlike [`ntt`](super::ntt), the transform works over `ℂ` with `f64` precision, so it needs
+//! no NTT-friendly prime and can convolve real sequences (e.g. probability distributions).
+//! The results are subject to rounding errors of order `max|ai| * max|bj| * n * 2^-53`.
+//!
+//! # Available items
+//!
+//! - [`Complex`]: A complex number with `f64` components
+//! - [`fft`], [`ifft`]: Radix-2 (inverse) FFT in place
+//! - [`convolution_complex`]: Convolution of complex sequences
+//! - [`convolution_real`]: Convolution of real sequences using half-size transforms
+//! - [`convolution_mod_fft`]: Convolution in `ℤ/MOD ℤ` by splitting values into 15-bit halves
+
+use std::f64::consts::PI;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::math::gf::GF;
+
+/// A complex number `re + im i`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex {
+    /// The real part.
+    pub re: f64,
+
+    /// The imaginary part.
+    pub im: f64,
+}
+
+impl Complex {
+    /// Creates the complex number `re + im i`.
+    #[inline]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns `e^(theta i) = cos(theta) + sin(theta) i`.
+    #[inline]
+    pub fn cis(theta: f64) -> Self {
+        Self::new(theta.cos(), theta.sin())
+    }
+
+    /// Returns the complex conjugate `re - im i`.
+    #[inline]
+    pub const fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the squared absolute value `re^2 + im^2`.
+    #[inline]
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the absolute value `sqrt(re^2 + im^2)`.
+    #[inline]
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+impl Mul<f64> for Complex {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.conj() * rhs.norm_sqr().recip()
+    }
+}
+impl Div<f64> for Complex {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+impl Neg for Complex {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Returns the roots of unity used by [`fft`] of length `n`.
+///
+/// `roots[h + j] = e^(πij/h)` for each power of two `h < n` and `0 <= j < h`.
+/// Every root is computed directly by `cos`/`sin` so that errors do not accumulate.
+fn roots(n: usize) -> Vec<Complex> {
+    let mut roots = vec![Complex::new(1.0, 0.0); n.max(2)];
+    let mut h = 2;
+    while h < n {
+        for j in 0..h {
+            roots[h + j] = Complex::cis(PI * j as f64 / h as f64);
+        }
+        h <<= 1;
+    }
+    roots
+}
+
+/// Performs Fast Fourier Transform (in-place).
+///
+/// ## Definition
+///
+/// For input sequence `a = (a0, a1, ..., a(n-1))`, computes output `f = (f0, f1, ..., f(n-1))`
+/// where:
+///
+/// `fk = sum_j {aj * omega^(jk)}`
+///
+/// where `omega = e^(2πi/n)`.
+///
+/// ## Requirements
+///
+/// - `data.len()` must be a power of two.
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n = data.len()`
+pub fn fft(data: &mut [Complex]) {
+    fft_with(data, &roots(data.len()));
+}
+
+/// Performs FFT (in-place) with the roots precomputed by [`roots`] for a length `>= data.len()`.
+fn fft_with(data: &mut [Complex], roots: &[Complex]) {
+    let n = data.len();
+    debug_assert!(
+        n.is_power_of_two(),
+        "invalid length: {} must be a power of two",
+        n
+    );
+    if n <= 1 {
+        return;
+    }
+    let k = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - k);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut h = 1;
+    while h < n {
+        for data in data.chunks_exact_mut(2 * h) {
+            let (x, y) = data.split_at_mut(h);
+            for ((x, y), w) in x.iter_mut().zip(y.iter_mut()).zip(&roots[h..2 * h]) {
+                let z = *y * *w;
+                (*x, *y) = (*x + z, *x - z);
+            }
+        }
+        h <<= 1;
+    }
+}
+
+/// Performs Inverse Fast Fourier Transform (in-place).
+///
+/// ## Definition
+///
+/// For input sequence `f = (f0, f1, ..., f(n-1))` (output of FFT),
+/// computes output `a = (a0, a1, ..., a(n-1))` where:
+///
+/// `ak = n^(-1) * sum_j {fj * omega^(-jk)}`
+///
+/// where `omega = e^(2πi/n)`.
+///
+/// This operation satisfies: `IFFT(FFT(a)) = a` for all sequence `a` (up to rounding errors).
+///
+/// ## Requirements
+///
+/// - `data.len()` must be a power of two.
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n = data.len()`
+pub fn ifft(data: &mut [Complex]) {
+    ifft_with(data, &roots(data.len()));
+}
+
+/// Performs IFFT (in-place) with the roots precomputed by [`roots`] for a length `>= data.len()`.
+fn ifft_with(data: &mut [Complex], roots: &[Complex]) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    // omega^(-jk) = omega^(j(n-k)), so the inverse is the forward transform read backwards.
+    fft_with(data, roots);
+    data[1..].reverse();
+    let inv = (n as f64).recip();
+    for x in data.iter_mut() {
+        *x = *x * inv;
+    }
+}
+
+/// Computes the convolution of two complex sequences using FFT.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i)`
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_complex(lhs: &[Complex], rhs: &[Complex]) -> Vec<Complex> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let len = lhs.len() + rhs.len() - 1;
+    let size = len.next_power_of_two();
+    let mut a = vec![Complex::default(); size];
+    let mut b = vec![Complex::default(); size];
+    a[..lhs.len()].copy_from_slice(lhs);
+    b[..rhs.len()].copy_from_slice(rhs);
+
+    let roots = roots(size);
+    fft_with(&mut a, &roots);
+    fft_with(&mut b, &roots);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x *= *y;
+    }
+    ifft_with(&mut a, &roots);
+    a.truncate(len);
+    a
+}
+
+/// Computes the convolution of two real sequences using FFT.
+///
+/// Each sequence of length `n` is packed into `n / 2` complex numbers
+/// (`z_j = a_(2j) + a_(2j+1) i`), so only three FFTs of half size are needed.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i)`
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_real(lhs: &[f64], rhs: &[f64]) -> Vec<f64> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let len = lhs.len() + rhs.len() - 1;
+    let size = len.next_power_of_two().max(2);
+    let half = size / 2;
+    let w = roots(size);
+    let pack = |a: &[f64]| {
+        let mut z = vec![Complex::default(); half];
+        for (z, a) in z.iter_mut().zip(a.chunks(2)) {
+            *z = Complex::new(a[0], a.get(1).copied().unwrap_or(0.0));
+        }
+        fft_with(&mut z, &w);
+        z
+    };
+    let za = pack(lhs);
+    let zb = pack(rhs);
+
+    // With `z = even + odd i`, the spectra of the even and odd parts are
+    // `E_k = (Z_k + conj Z_(-k)) / 2` and `O_k = (Z_k - conj Z_(-k)) / 2i`,
+    // and the full spectrum is `X_k = E_k + w^k O_k`, `X_(k+half) = E_k - w^k O_k`.
+    let spectrum = |z: &[Complex], k: usize| {
+        let zr = z[(half - k) & (half - 1)].conj();
+        let even = (z[k] + zr) * 0.5;
+        let odd = (z[k] - zr) * Complex::new(0.0, -0.5);
+        let odd = odd * w[half + k];
+        (even + odd, even - odd)
+    };
+    let mut zc = vec![Complex::default(); half];
+    for (k, zc) in zc.iter_mut().enumerate() {
+        let (a0, a1) = spectrum(&za, k);
+        let (b0, b1) = spectrum(&zb, k);
+        let (c0, c1) = (a0 * b0, a1 * b1);
+        let even = (c0 + c1) * 0.5;
+        let odd = (c0 - c1) * 0.5 * w[half + k].conj();
+        *zc = even + odd * Complex::new(0.0, 1.0);
+    }
+    ifft_with(&mut zc, &w);
+
+    let mut c: Vec<f64> = zc.into_iter().flat_map(|z| [z.re, z.im]).collect();
+    c.truncate(len);
+    c
+}
+
+/// Computes the convolution of two sequences in `ℤ/MOD ℤ` using FFT.
+///
+/// Each value is taken in `(-MOD/2, MOD/2]` and split as `x = x1 * 2^15 + x0` with
+/// `-2^14 <= x0 < 2^14`, and the four partial products are obtained from two forward and two
+/// inverse FFTs. Keeping the halves centered at zero reduces the rounding errors.
+///
+/// ## Definition
+///
+/// For sequences `a = (a0, a1, ..., a(n-1))` and `b = (b0, b1, ..., b(m-1))`,
+/// computes the convolution `c = (c0, c1, ..., c(n+m-2))` where:
+///
+/// `ck = sum i ai * b(k-i) mod MOD`
+///
+/// ## Requirements
+///
+/// - `MOD < 2^30`
+/// - `lhs.len() + rhs.len() - 1 <= 2^20`
+///   (otherwise rounding errors may give wrong results)
+///
+/// ## Complexity
+///
+/// `O(n log n)` where `n` is the smallest power of two >= `lhs.len() + rhs.len() - 1`.
+pub fn convolution_mod_fft<const MOD: u32>(lhs: &[GF<MOD>], rhs: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    const SHIFT: u32 = 15;
+
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let len = lhs.len() + rhs.len() - 1;
+    let size = len.next_power_of_two();
+    let roots = roots(size);
+    let split = |a: &[GF<MOD>]| {
+        let mut z = vec![Complex::default(); size];
+        for (z, a) in z.iter_mut().zip(a) {
+            let mut x = a.value() as i64;
+            if x > MOD as i64 / 2 {
+                x -= MOD as i64;
+            }
+            let x1 = (x + (1 << (SHIFT - 1))) >> SHIFT;
+            let x0 = x - (x1 << SHIFT);
+            *z = Complex::new(x1 as f64, x0 as f64);
+        }
+        fft_with(&mut z, &roots);
+        z
+    };
+    let za = split(lhs);
+    let zb = split(rhs);
+
+    // `za = A1 + A0 i`, so `A1 = (Z_k + conj Z_(-k)) / 2` and `A0 = (Z_k - conj Z_(-k)) / 2i`.
+    // Then `A1 * zb = A1 B1 + A1 B0 i` and `A0 * zb = A0 B1 + A0 B0 i`.
+    let mut high = vec![Complex::default(); size];
+    let mut low = vec![Complex::default(); size];
+    for k in 0..size {
+        let zr = za[(size - k) & (size - 1)].conj();
+        let a1 = (za[k] + zr) * 0.5;
+        let a0 = (za[k] - zr) * Complex::new(0.0, -0.5);
+        high[k] = a1 * zb[k];
+        low[k] = a0 * zb[k];
+    }
+    ifft_with(&mut high, &roots);
+    ifft_with(&mut low, &roots);
+
+    let round = |x: f64| (x.round() as i64).rem_euclid(MOD as i64) as u64;
+    high.into_iter()
+        .zip(low)
+        .take(len)
+        .map(|(high, low)| {
+            let c2 = round(high.re);
+            let c1 = (round(high.im) + round(low.re)) % MOD as u64;
+            let c0 = round(low.im);
+            let c = ((c2 << SHIFT) % MOD as u64 + c1) % MOD as u64;
+            GF::new((((c << SHIFT) + c0) % MOD as u64) as u32)
+        })
+        .collect()
+}
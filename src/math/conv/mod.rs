@@ -3,5 +3,9 @@
 //! # Avaliable kind of Convolution
 //!
 //! - [`ntt`]: Number Theoretic Transform (NTT)
+//! - [`fft`]: Fast Fourier Transform (FFT) over complex numbers
+//! - [`arbitrary_mod`]: Convolution under arbitrary modulus and exact integer convolution
 
+pub mod arbitrary_mod;
+pub mod fft;
 pub mod ntt;
@@ -7,6 +7,7 @@ use std::sync::{LazyLock, Mutex};
 
 use crate::math::gf::GF;
 use crate::math::modint::pow_mod;
+use crate::math::montgomery::Montgomery32;
 use crate::math::prime::primitive_root;
 
 /// Maximum number of levels supported for NTT.
@@ -203,22 +204,83 @@ pub fn ntt_conv<const MOD: u32>(lhs: &[GF<MOD>], rhs: &[GF<MOD>]) -> Vec<GF<MOD>
     if lhs.is_empty() || rhs.is_empty() {
         return vec![];
     }
+    let mont = Mont::<MOD>::CTX;
     let size = (lhs.len() + rhs.len() - 1).next_power_of_two();
-    let mut a = vec![GF::new(0); size];
-    let mut b = vec![GF::new(0); size];
-    a[..lhs.len()].copy_from_slice(lhs);
-    b[..rhs.len()].copy_from_slice(rhs);
-
-    ntt(&mut a);
-    ntt(&mut b);
+    let mut a = vec![0; size];
+    let mut b = vec![0; size];
+    for (x, y) in a.iter_mut().zip(lhs) {
+        *x = mont.to_mont(y.value());
+    }
+    for (x, y) in b.iter_mut().zip(rhs) {
+        *x = mont.to_mont(y.value());
+    }
 
-    let mut c = a
-        .iter()
-        .zip(b.iter())
-        .map(|(a, b)| a * b)
-        .collect::<Vec<_>>();
-    intt(&mut c);
+    ntt_mont::<MOD>(&mut a);
+    ntt_mont::<MOD>(&mut b);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x = mont.mul(*x, *y);
+    }
+    intt_mont::<MOD>(&mut a);
 
+    let mut c: Vec<GF<MOD>> = a.into_iter().map(|x| GF::new(mont.from_mont(x))).collect();
     c.truncate(lhs.len() + rhs.len() - 1);
     c
 }
+
+/// Montgomery context of a compile-time modulus.
+struct Mont<const MOD: u32>;
+
+impl<const MOD: u32> Mont<MOD> {
+    /// The Montgomery context for `MOD`, computed at compile time.
+    const CTX: Montgomery32 = Montgomery32::new(MOD);
+}
+
+/// Performs NTT (in-place) on values in Montgomery form.
+///
+/// Same as [`ntt`], but avoids `%` in the butterflies. Only worth it when the conversions into
+/// and out of Montgomery form are shared by several transforms, as in [`ntt_conv`].
+fn ntt_mont<const MOD: u32>(data: &mut [u32]) {
+    let mont = Mont::<MOD>::CTX;
+    let n = data.len();
+    let k = n.trailing_zeros() as usize;
+    let (roots, _) = get_ntt_roots(MOD);
+    let roots = roots.map(|r| mont.to_mont(r));
+    for t in (0..k).rev() {
+        let t = 1 << t;
+        let mut coef = mont.one();
+        for (i, data) in data.chunks_exact_mut(2 * t).enumerate() {
+            let (x, y) = data.split_at_mut(t);
+            for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                let z = mont.mul(*y, coef);
+                (*x, *y) = (mont.add(*x, z), mont.sub(*x, z));
+            }
+            coef = mont.mul(coef, roots[(!i).trailing_zeros() as usize]);
+        }
+    }
+}
+
+/// Performs INTT (in-place) on values in Montgomery form.
+///
+/// Same as [`intt`], but avoids `%` in the butterflies.
+fn intt_mont<const MOD: u32>(data: &mut [u32]) {
+    let mont = Mont::<MOD>::CTX;
+    let n = data.len();
+    let k = n.trailing_zeros() as usize;
+    let (_, inv_roots) = get_ntt_roots(MOD);
+    let inv_roots = inv_roots.map(|r| mont.to_mont(r));
+    for t in 0..k {
+        let t = 1 << t;
+        let mut coef = mont.one();
+        for (i, data) in data.chunks_exact_mut(2 * t).enumerate() {
+            let (x, y) = data.split_at_mut(t);
+            for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                (*x, *y) = (mont.add(*x, *y), mont.mul(mont.sub(*x, *y), coef));
+            }
+            coef = mont.mul(coef, inv_roots[(!i).trailing_zeros() as usize]);
+        }
+    }
+    let coef = mont.pow(mont.to_mont(MOD.div_ceil(2)), k as u64);
+    for x in data.iter_mut() {
+        *x = mont.mul(*x, coef);
+    }
+}
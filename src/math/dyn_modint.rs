@@ -0,0 +1,408 @@
+//! Runtime modular integer
+//!
+//! [`DynModInt`] is an element of `ℤ/mℤ` whose modulus `m` is chosen at runtime, e.g. read from
+//! the input. Unlike [`GF<MOD>`], the modulus need not be prime.
+//!
+//! The modulus is stored per thread and set by [`DynModInt::set_modulus`]. Multiplication uses
+//! [`Barrett`] reduction, which replaces the division by a runtime modulus with multiplications.
+//!
+//! # Interoperability
+//!
+//! - `From<GF<MOD>>` and [`DynModInt::to_gf`] convert between the two types when the current
+//!   modulus is `MOD`, so tables of [`Comb<MOD>`](super::gf_comb::Comb) can be reused.
+//! - [`ntt_conv_dyn`]: Convolution via [`ntt_conv`] when the current modulus is an NTT-friendly
+//!   prime.
+
+use std::cell::Cell;
+
+use super::conv::ntt::ntt_conv;
+use super::gf::GF;
+
+/// Barrett reduction for a fixed modulus `1 <= m < 2^32`.
+///
+/// Precomputes `⌈2^64 / m⌉` so that `z mod m` for `z < 2^64` is computed with multiplications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Barrett {
+    /// The modulus.
+    m: u32,
+
+    /// `⌈2^64 / m⌉` (wrapped to `0` when `m == 1`).
+    im: u64,
+}
+
+impl Barrett {
+    /// Creates the reduction context for modulus `m`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `m == 0`.
+    pub const fn new(m: u32) -> Self {
+        assert!(m > 0, "modulus must be positive");
+        Self {
+            m,
+            im: (u64::MAX / m as u64).wrapping_add(1),
+        }
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    pub const fn modulus(&self) -> u32 {
+        self.m
+    }
+
+    /// Returns `a * b mod m` for `a, b < m`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        let z = a as u64 * b as u64;
+        // x is either floor(z / m) or floor(z / m) + 1.
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let (v, borrow) = z.overflowing_sub(x.wrapping_mul(self.m as u64));
+        if borrow {
+            v.wrapping_add(self.m as u64) as u32
+        } else {
+            v as u32
+        }
+    }
+}
+
+thread_local! {
+    /// The modulus of [`DynModInt`] on the current thread.
+    static BARRETT: Cell<Barrett> = const { Cell::new(Barrett::new(998_244_353)) };
+}
+
+/// An element of `ℤ/mℤ` with a runtime modulus `m`.
+///
+/// The modulus is shared by all values on the current thread (default `998244353`).
+/// Changing the modulus invalidates existing values.
+///
+/// # Requirements
+///
+/// `1 <= m < 2^32`. Division requires the divisor to be coprime to `m`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    /// The representative of the element in `ℤ/mℤ` (satisfy `0 <= value < m`)
+    value: u32,
+}
+
+impl DynModInt {
+    /// Sets the modulus of the current thread.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `m == 0`.
+    pub fn set_modulus(m: u32) {
+        BARRETT.with(|b| b.set(Barrett::new(m)));
+    }
+
+    /// Returns the modulus of the current thread.
+    #[inline]
+    pub fn modulus() -> u32 {
+        Self::barrett().m
+    }
+
+    /// Returns the reduction context of the current thread.
+    #[inline]
+    fn barrett() -> Barrett {
+        BARRETT.with(Cell::get)
+    }
+
+    /// Returns zero (additive identity)
+    #[inline]
+    pub const fn zero() -> Self {
+        Self { value: 0 }
+    }
+
+    /// Returns one (multiplicative identity)
+    ///
+    /// This is `0` if the modulus is `1`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::new(1)
+    }
+
+    /// Creates a new element of `ℤ/mℤ`.
+    ///
+    /// ## Returns
+    ///
+    /// `value % m` becomes representative.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn new(value: u32) -> Self {
+        Self {
+            value: value % Self::modulus(),
+        }
+    }
+
+    /// Returns the representative in `0..m`.
+    #[inline]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns `self^n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    pub fn pow(&self, mut n: u64) -> Self {
+        let b = Self::barrett();
+        let mut res = Self::one().value;
+        let mut base = self.value;
+        while n > 0 {
+            if n & 1 == 1 {
+                res = b.mul(res, base);
+            }
+            base = b.mul(base, base);
+            n >>= 1;
+        }
+        Self { value: res }
+    }
+
+    /// Returns the multiplicative inverse of `self` if it exists.
+    ///
+    /// Uses the extended Euclidean algorithm, so the modulus need not be prime.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(x)`: `self * x = 1`
+    /// - `None`: `gcd(self, m) != 1`, so no inverse exists.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log m)`
+    pub fn checked_inv(&self) -> Option<Self> {
+        let m = Self::modulus() as i64;
+        // Invariant: a ≡ x * self and b ≡ y * self (mod m).
+        let (mut a, mut b) = (self.value as i64, m);
+        let (mut x, mut y) = (1i64, 0i64);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        (a == 1).then(|| Self {
+            value: x.rem_euclid(m) as u32,
+        })
+    }
+
+    /// Returns the multiplicative inverse of `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `gcd(self, m) != 1` (no inverse exists).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log m)`
+    pub fn inv(&self) -> Self {
+        self.checked_inv()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {}", self.value, Self::modulus()))
+    }
+
+    /// Converts `self` to [`GF<MOD>`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the current modulus is not `MOD`.
+    pub fn to_gf<const MOD: u32>(&self) -> GF<MOD> {
+        assert_eq!(Self::modulus(), MOD, "modulus mismatch");
+        GF::new(self.value)
+    }
+}
+
+impl<const MOD: u32> From<GF<MOD>> for DynModInt {
+    /// Converts an element of [`GF<MOD>`] by its representative.
+    ///
+    /// The current modulus should be `MOD`, otherwise the value is reduced modulo the current
+    /// modulus.
+    fn from(x: GF<MOD>) -> Self {
+        debug_assert_eq!(Self::modulus(), MOD, "modulus mismatch");
+        Self::new(x.value())
+    }
+}
+
+/// Returns the convolution of `lhs` and `rhs` modulo the current modulus using NTT.
+///
+/// ## Returns
+///
+/// - `Some(c)`: `c[k] = Σ_{i + j = k} lhs[i] * rhs[j]`, if the current modulus is one of the
+///   NTT-friendly primes `998244353`, `754974721`, `167772161` and `469762049`.
+/// - `None`: The current modulus is not supported.
+///
+/// ## Complexity
+///
+/// `O((n + m) log (n + m))`
+pub fn ntt_conv_dyn(lhs: &[DynModInt], rhs: &[DynModInt]) -> Option<Vec<DynModInt>> {
+    fn run<const MOD: u32>(lhs: &[DynModInt], rhs: &[DynModInt]) -> Vec<DynModInt> {
+        let a: Vec<GF<MOD>> = lhs.iter().map(DynModInt::to_gf).collect();
+        let b: Vec<GF<MOD>> = rhs.iter().map(DynModInt::to_gf).collect();
+        ntt_conv(&a, &b).into_iter().map(DynModInt::from).collect()
+    }
+    match DynModInt::modulus() {
+        998_244_353 => Some(run::<998_244_353>(lhs, rhs)),
+        754_974_721 => Some(run::<754_974_721>(lhs, rhs)),
+        167_772_161 => Some(run::<167_772_161>(lhs, rhs)),
+        469_762_049 => Some(run::<469_762_049>(lhs, rhs)),
+        _ => None,
+    }
+}
+
+use std::fmt::{Debug, Display, Formatter, Result};
+impl Debug for DynModInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value)
+    }
+}
+impl Display for DynModInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+impl Neg for DynModInt {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        if self.value > 0 {
+            self.value = Self::modulus() - self.value;
+        }
+        self
+    }
+}
+impl AddAssign<DynModInt> for DynModInt {
+    fn add_assign(&mut self, rhs: DynModInt) {
+        let m = Self::modulus();
+        let (v, overflow) = self.value.overflowing_add(rhs.value);
+        self.value = if overflow || v >= m {
+            v.wrapping_sub(m)
+        } else {
+            v
+        };
+    }
+}
+impl SubAssign<DynModInt> for DynModInt {
+    fn sub_assign(&mut self, rhs: DynModInt) {
+        let (v, borrow) = self.value.overflowing_sub(rhs.value);
+        self.value = if borrow {
+            v.wrapping_add(Self::modulus())
+        } else {
+            v
+        };
+    }
+}
+impl MulAssign<DynModInt> for DynModInt {
+    fn mul_assign(&mut self, rhs: DynModInt) {
+        self.value = Self::barrett().mul(self.value, rhs.value);
+    }
+}
+impl DivAssign<DynModInt> for DynModInt {
+    fn div_assign(&mut self, rhs: DynModInt) {
+        self.value = Self::barrett().mul(self.value, rhs.inv().value);
+    }
+}
+
+macro_rules! dyn_modint_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl $trait_assign<&DynModInt> for DynModInt {
+            fn $fn_assign(&mut self, rhs: &DynModInt) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<T: Into<DynModInt>> $trait<T> for DynModInt {
+            type Output = DynModInt;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl $trait<&DynModInt> for DynModInt {
+            type Output = DynModInt;
+            fn $fn(self, rhs: &DynModInt) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<T: Into<DynModInt>> $trait<T> for &DynModInt {
+            type Output = DynModInt;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl $trait<&DynModInt> for &DynModInt {
+            type Output = DynModInt;
+            fn $fn(self, rhs: &DynModInt) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+
+dyn_modint_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+
+use std::iter::{Product, Sum};
+impl Sum for DynModInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, a| acc + a)
+    }
+}
+impl<'a> Sum<&'a Self> for DynModInt {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+impl Product for DynModInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, a| acc * a)
+    }
+}
+impl<'a> Product<&'a Self> for DynModInt {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+macro_rules! dyn_modint_from_signed {
+    ($($t:ty), *) => {
+        $(
+            impl From<$t> for DynModInt {
+                fn from(x: $t) -> Self {
+                    Self {
+                        value: (x as i128).rem_euclid(Self::modulus() as i128) as u32,
+                    }
+                }
+            }
+        )*
+    };
+}
+dyn_modint_from_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! dyn_modint_from_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl From<$t> for DynModInt {
+                fn from(x: $t) -> Self {
+                    Self {
+                        value: (x as u128 % Self::modulus() as u128) as u32,
+                    }
+                }
+            }
+        )*
+    };
+}
+dyn_modint_from_unsigned!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,406 @@
+//! Formal Power Series
+//!
+//! This structure represents a **formal power series** `f(x) = sum_i f_i x^i` over `ℤ/MOD ℤ`,
+//! stored as its first coefficients.
+//!
+//! Operations which produce infinite series (`inv`, `log`, `exp`, `pow`, `sqrt`) take the number
+//! of terms `n` to compute and return `f mod x^n`. They use Newton iteration on top of
+//! [`ntt`]/[`intt`], so `MOD` must be NTT-friendly (e.g. `998244353`).
+//!
+//! # Available operations
+//!
+//! - `+`, `-`, `*`: Addition, subtraction and multiplication (convolution)
+//! - [`Fps::inv`]: Calculate `1 / f`
+//! - [`Fps::log`]: Calculate `log f`
+//! - [`Fps::exp`]: Calculate `exp f`
+//! - [`Fps::pow`]: Calculate `f^k`
+//! - [`Fps::sqrt`]: Calculate `sqrt f`
+//! - [`Fps::derivative`], [`Fps::integral`]: Formal derivative and integral
+
+use std::ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::conv::ntt::{intt, ntt, ntt_conv};
+use super::gf::GF;
+
+/// A structure representating a *formal power series* over `ℤ/MOD ℤ`.
+///
+/// `self[i]` is the coefficient of `x^i`; all coefficients beyond `self.len()` are zero.
+/// The coefficients are accessible as a `Vec<GF<MOD>>` through `Deref`.
+///
+/// # Type Parameters
+///
+/// - `MOD`: The prime modulus
+///   (satisfy `MOD` is a prime of the form `c * 2^k + 1`)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fps<const MOD: u32> {
+    /// Coefficients in ascending order of degree.
+    coef: Vec<GF<MOD>>,
+}
+
+impl<const MOD: u32> Fps<MOD> {
+    /// Creates a formal power series from coefficients in ascending order of degree.
+    #[inline]
+    pub fn new(coef: Vec<GF<MOD>>) -> Self {
+        Self { coef }
+    }
+
+    /// Returns the zero series.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { coef: vec![] }
+    }
+
+    /// Returns the series `1`.
+    #[inline]
+    pub fn one() -> Self {
+        Self {
+            coef: vec![GF::one()],
+        }
+    }
+
+    /// Returns the coefficients as a `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<GF<MOD>> {
+        self.coef
+    }
+
+    /// Returns `self mod x^n` with exactly `n` coefficients.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    pub fn prefix(&self, n: usize) -> Self {
+        let mut coef = self.coef[..n.min(self.len())].to_vec();
+        coef.resize(n, GF::zero());
+        Self { coef }
+    }
+
+    /// Returns the formal derivative `f'(x) = sum_i i f_i x^(i-1)`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)` where `n = self.len()`
+    pub fn derivative(&self) -> Self {
+        let coef = (1..self.len())
+            .map(|i| self[i] * GF::new(i as u32))
+            .collect();
+        Self { coef }
+    }
+
+    /// Returns the formal integral `sum_i f_i / (i+1) x^(i+1)` (with constant term `0`).
+    ///
+    /// ## Requirements
+    ///
+    /// - `self.len() < MOD`
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)` where `n = self.len()`
+    pub fn integral(&self) -> Self {
+        let inv = inverses::<MOD>(self.len() + 1);
+        let mut coef = Vec::with_capacity(self.len() + 1);
+        coef.push(GF::zero());
+        coef.extend(self.iter().zip(&inv[1..]).map(|(a, b)| a * b));
+        Self { coef }
+    }
+
+    /// Returns `1 / f mod x^n`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `self[0] == 0` (the series is not invertible).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)`
+    pub fn inv(&self, n: usize) -> Self {
+        debug_assert!(
+            !self.is_empty() && self[0] != GF::zero(),
+            "The constant term must not be 0"
+        );
+        // Newton iteration: g <- g - g (f g - 1), doubling the precision `m` each step.
+        let mut g = vec![self[0].inv()];
+        let mut m = 1;
+        while m < n {
+            let mut a = self.prefix(2 * m).into_vec();
+            let mut b = g.clone();
+            b.resize(2 * m, GF::zero());
+            ntt(&mut a);
+            ntt(&mut b);
+            for (x, y) in a.iter_mut().zip(&b) {
+                *x *= y;
+            }
+            intt(&mut a);
+            // f g = 1 (mod x^m), and the cyclic wrap-around only affects the first `m` terms.
+            a[..m].fill(GF::zero());
+            ntt(&mut a);
+            for (x, y) in a.iter_mut().zip(&b) {
+                *x *= y;
+            }
+            intt(&mut a);
+            g.extend(a[m..].iter().map(|x| -*x));
+            m *= 2;
+        }
+        g.truncate(n);
+        Self { coef: g }
+    }
+
+    /// Returns `log f mod x^n`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `self[0] != 1`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)`
+    pub fn log(&self, n: usize) -> Self {
+        debug_assert!(
+            !self.is_empty() && self[0] == GF::one(),
+            "The constant term must be 1"
+        );
+        if n == 0 {
+            return Self::zero();
+        }
+        // log f = integral(f' / f)
+        let f = self.prefix(n);
+        let mut h = &f.derivative() * &f.inv(n);
+        h.truncate(n - 1);
+        h.integral()
+    }
+
+    /// Returns `exp f mod x^n`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `self[0] != 0`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)`
+    pub fn exp(&self, n: usize) -> Self {
+        debug_assert!(
+            self.is_empty() || self[0] == GF::zero(),
+            "The constant term must be 0"
+        );
+        if n == 0 {
+            return Self::zero();
+        }
+        // Newton iteration: g <- g (1 - log g + f), doubling the precision `m` each step.
+        let mut g = Self::one();
+        let mut m = 1;
+        while m < n {
+            m *= 2;
+            let mut h = self.prefix(m) - g.log(m);
+            h[0] += GF::one();
+            g = &g * &h;
+            g.truncate(m);
+        }
+        g.truncate(n);
+        g
+    }
+
+    /// Returns `f^k mod x^n`.
+    ///
+    /// Leading zeros are allowed: if `f = c x^d (1 + g)` with `c != 0`, then
+    /// `f^k = c^k x^(dk) exp(k log(1 + g))`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)`
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Self::one().prefix(n);
+        }
+        let Some(d) = self.iter().position(|x| *x != GF::zero()) else {
+            return Self::zero().prefix(n);
+        };
+        if d as u128 * k as u128 >= n as u128 {
+            return Self::zero().prefix(n);
+        }
+        let shift = d * k as usize;
+        let c = self[d];
+        let c_inv = c.inv();
+        let g = Self {
+            coef: self[d..]
+                .iter()
+                .take(n - shift)
+                .map(|x| x * c_inv)
+                .collect(),
+        };
+        let mut g = g.log(n - shift);
+        let k_mod = GF::new((k % MOD as u64) as u32);
+        for x in g.iter_mut() {
+            *x *= k_mod;
+        }
+        let c_pow = c.pow((k % (MOD as u64 - 1)) as u32);
+        let mut coef = vec![GF::zero(); shift];
+        coef.extend(g.exp(n - shift).iter().map(|x| x * c_pow));
+        Self { coef }
+    }
+
+    /// Returns `g mod x^n` such that `g^2 = f`, or `None` if no such series exists.
+    ///
+    /// `f = c x^d (1 + h)` has a square root if and only if `d` is even and `c` is a square in
+    /// `ℤ/MOD ℤ`; then `g = sqrt(c) x^(d/2) exp(log(1 + h) / 2)`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)`
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let Some(d) = self.iter().position(|x| *x != GF::zero()) else {
+            return Some(Self::zero().prefix(n));
+        };
+        if d % 2 == 1 {
+            return None;
+        }
+        let c = self[d];
+        let c_sqrt = c.sqrt()?;
+        let shift = d / 2;
+        if shift >= n {
+            return Some(Self::zero().prefix(n));
+        }
+        let c_inv = c.inv();
+        let g = Self {
+            coef: self[d..]
+                .iter()
+                .take(n - shift)
+                .map(|x| x * c_inv)
+                .collect(),
+        };
+        let mut g = g.log(n - shift);
+        let half = GF::new(2).inv();
+        for x in g.iter_mut() {
+            *x *= half;
+        }
+        let mut coef = vec![GF::zero(); shift];
+        coef.extend(g.exp(n - shift).iter().map(|x| x * c_sqrt));
+        Some(Self { coef })
+    }
+}
+
+/// Returns `[0, 1^(-1), 2^(-1), ..., (n-1)^(-1)]` in `ℤ/MOD ℤ` (the first element is a dummy).
+///
+/// Uses `i^(-1) = -(MOD / i) * (MOD mod i)^(-1)`.
+fn inverses<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    debug_assert!(
+        n <= MOD as usize,
+        "invalid length: {} must not be greater than {}",
+        n,
+        MOD
+    );
+    let mut inv = vec![GF::zero(); n.max(2)];
+    inv[1] = GF::one();
+    for i in 2..n {
+        inv[i] = -inv[MOD as usize % i] * GF::new(MOD / i as u32);
+    }
+    inv.truncate(n);
+    inv
+}
+
+impl<const MOD: u32> Deref for Fps<MOD> {
+    type Target = Vec<GF<MOD>>;
+    fn deref(&self) -> &Self::Target {
+        &self.coef
+    }
+}
+impl<const MOD: u32> DerefMut for Fps<MOD> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.coef
+    }
+}
+
+impl<const MOD: u32> From<Vec<GF<MOD>>> for Fps<MOD> {
+    fn from(coef: Vec<GF<MOD>>) -> Self {
+        Self { coef }
+    }
+}
+
+impl<const MOD: u32> Neg for Fps<MOD> {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        for x in self.iter_mut() {
+            *x = -*x;
+        }
+        self
+    }
+}
+impl<const MOD: u32> Neg for &Fps<MOD> {
+    type Output = Fps<MOD>;
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<const MOD: u32> AddAssign<&Fps<MOD>> for Fps<MOD> {
+    fn add_assign(&mut self, rhs: &Fps<MOD>) {
+        if self.len() < rhs.len() {
+            self.resize(rhs.len(), GF::zero());
+        }
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x += y;
+        }
+    }
+}
+impl<const MOD: u32> SubAssign<&Fps<MOD>> for Fps<MOD> {
+    fn sub_assign(&mut self, rhs: &Fps<MOD>) {
+        if self.len() < rhs.len() {
+            self.resize(rhs.len(), GF::zero());
+        }
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x -= y;
+        }
+    }
+}
+impl<const MOD: u32> MulAssign<&Fps<MOD>> for Fps<MOD> {
+    fn mul_assign(&mut self, rhs: &Fps<MOD>) {
+        self.coef = ntt_conv(&self.coef, &rhs.coef);
+    }
+}
+
+macro_rules! fps_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl<const MOD: u32> $trait_assign<Fps<MOD>> for Fps<MOD> {
+            fn $fn_assign(&mut self, rhs: Fps<MOD>) {
+                self.$fn_assign(&rhs);
+            }
+        }
+        impl<const MOD: u32> $trait<Fps<MOD>> for Fps<MOD> {
+            type Output = Fps<MOD>;
+            fn $fn(mut self, rhs: Fps<MOD>) -> Self::Output {
+                self.$fn_assign(&rhs);
+                self
+            }
+        }
+        impl<const MOD: u32> $trait<&Fps<MOD>> for Fps<MOD> {
+            type Output = Fps<MOD>;
+            fn $fn(mut self, rhs: &Fps<MOD>) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+        impl<const MOD: u32> $trait<Fps<MOD>> for &Fps<MOD> {
+            type Output = Fps<MOD>;
+            fn $fn(self, rhs: Fps<MOD>) -> Self::Output {
+                self.clone().$fn(&rhs)
+            }
+        }
+        impl<const MOD: u32> $trait<&Fps<MOD>> for &Fps<MOD> {
+            type Output = Fps<MOD>;
+            fn $fn(self, rhs: &Fps<MOD>) -> Self::Output {
+                self.clone().$fn(rhs)
+            }
+        }
+    )*};
+}
+
+fps_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+}
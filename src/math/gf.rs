@@ -6,6 +6,8 @@
 //! Every galois field of prime order is isomorphic to `ℤ/pℤ` which `p` is prime.
 //!
 
+use super::modint::sqrt_mod;
+
 /// A structure representating a *galois field*.
 ///
 /// Represents integers modulo a prime number `MOD`, forming the finite field `ℤ/MOD ℤ`.
@@ -43,6 +45,12 @@ impl<const MOD: u32> GF<MOD> {
         Self { value: 1 }
     }
 
+    /// Returns the representative in `0..MOD`.
+    #[inline]
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
     /// Creates a new element of `ℤ/MOD ℤ`.
     ///
     /// ## Parameters
@@ -115,6 +123,20 @@ impl<const MOD: u32> GF<MOD> {
         // a^(MOD-2) ≡ a^(-1) is correct.
         self.pow(MOD - 2)
     }
+
+    /// Returns `x` such that `x^2 = self`, or `None` if `self` is not a square in `ℤ/MOD ℤ`.
+    ///
+    /// ## Returns
+    ///
+    /// One of the square roots (the other one is `-x`).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log^2 MOD)`
+    #[inline]
+    pub fn sqrt(&self) -> Option<Self> {
+        sqrt_mod(self.value as u64, MOD as u64).map(|x| Self::new(x as u32))
+    }
 }
 
 use std::fmt::{Debug, Display, Formatter, Result};
@@ -243,18 +265,40 @@ impl<'a, const MOD: u32> Product<&'a Self> for GF<MOD> {
 /// # Syntax
 ///
 /// - `gf!(value)`: Creates an element where the modulus is inferred from the context type.
-/// - `gf!(value, MOD)`: Creates an element with an explicit modulus.
+/// - `gf!(value, MOD)`: Creates an element of `GF<MOD>` with an explicit modulus.
+/// - `gf!(value, GF64<MOD>)`: Creates an element of [`GF64<MOD>`](super::gf64::GF64).
+///
+/// The inferred form works for both `GF` and `GF64`.
 ///
 /// # Note
 ///
 /// `GF::new()` or `GF::from()` may be more appropriate.
+///
+/// # Examples
+///
+/// ```
+/// use math_optim::gf;
+/// use math_optim::math::{gf::GF, gf64::GF64};
+///
+/// let a = gf!(5, 998244353);
+/// let b: GF<998244353> = gf!(-3);
+/// assert_eq!((a + b).value(), 2);
+///
+/// const P: u64 = (1 << 61) - 1;
+/// let c = gf!(5, GF64<P>);
+/// let d: GF64<P> = gf!(-3);
+/// assert_eq!((c + d).value(), 2);
+/// ```
 #[macro_export]
 macro_rules! gf {
     ($value:expr) => {
-        $crate::GF::from($value)
+        ::core::convert::From::from($value)
+    };
+    ($value:expr, GF64<$p:tt>) => {
+        $crate::math::gf64::GF64::<$p>::from($value)
     };
     ($value:expr, $p:expr) => {
-        $crate::GF::<$p>::from($value)
+        $crate::math::gf::GF::<$p>::from($value)
     };
 }
 macro_rules! gf_new_from_signed {
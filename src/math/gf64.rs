@@ -0,0 +1,286 @@
+//! Galois Field over 64-bit moduli
+//!
+//! This structure represents the **Galois Field** `ℤ/pℤ` for a prime `p < 2^64`.
+//!
+//! It is the 64-bit counterpart of [`GF`](super::gf::GF), for moduli such as `2^61 - 1`
+//! (rolling hashes) or `4611686018427387847`. Multiplication uses Montgomery reduction
+//! ([`Montgomery64`]) instead of `u128 %`.
+
+use super::montgomery::Montgomery64;
+
+/// A structure representating a *galois field* with a 64-bit modulus.
+///
+/// Represents integers modulo a prime number `MOD`, forming the finite field `ℤ/MOD ℤ`.
+///
+/// # Type Parameters
+///
+/// - `MOD`: The prime modulus
+///   (satisfy `MOD` is an odd prime)
+///
+/// # Requirements
+///
+/// **`MOD` must be an odd prime.** Non-prime moduli do not form a field, and Montgomery
+/// reduction requires an odd modulus (an even `MOD` fails to compile).
+///
+/// # Examples
+///
+/// ```
+/// use math_optim::gf;
+/// use math_optim::math::gf64::GF64;
+///
+/// const P: u64 = (1 << 61) - 1;
+/// const Q: u64 = 18446744073709551557; // The largest prime below 2^64
+///
+/// let a = gf!(123456789, GF64<P>);
+/// let b: GF64<P> = gf!(P + 987654321);
+/// assert_eq!((a * b).value(), 123456789 * 987654321);
+/// assert_eq!((a - b).value(), P - 864197532);
+/// assert_eq!(a * a.inv(), GF64::one());
+/// assert_eq!(a.pow(P - 2), a.inv());
+/// assert_eq!(a.pow(P - 1), GF64::one());
+///
+/// let x = gf!(-2, GF64<Q>);
+/// let y: GF64<Q> = gf!(u64::MAX);
+/// assert_eq!(x.value(), Q - 2);
+/// assert_eq!(y.value(), u64::MAX - Q);
+/// assert_eq!(x.inv().inv(), x);
+/// assert_eq!(x * x.inv(), GF64::one());
+/// assert_eq!(x.pow(Q - 1), GF64::one());
+/// assert_eq!((x / y) * y, x);
+///
+/// // 1 + 2 + ... + 20 and 20! (which is smaller than `Q`, but not `P`).
+/// let v: Vec<GF64<Q>> = (1..=20u64).map(|i| gf!(i)).collect();
+/// assert_eq!(v.iter().sum::<GF64<Q>>().value(), 210);
+/// assert_eq!(v.iter().product::<GF64<Q>>().value(), 2432902008176640000);
+/// let w: Vec<GF64<P>> = (1..=20u64).map(|i| gf!(i)).collect();
+/// assert_eq!(w.into_iter().product::<GF64<P>>().value(), 2432902008176640000 % P);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GF64<const MOD: u64> {
+    /// The Montgomery form `xR mod MOD` of the element `x` (satisfy `0 <= value < MOD`)
+    value: u64,
+}
+
+impl<const MOD: u64> GF64<MOD> {
+    /// The Montgomery context for `MOD`, computed at compile time.
+    const CTX: Montgomery64 = Montgomery64::new(MOD);
+
+    /// Returns the modulus.
+    #[inline]
+    pub const fn modulus() -> u64 {
+        MOD
+    }
+
+    /// Returns zero (additive identity)
+    #[inline]
+    pub const fn zero() -> Self {
+        Self { value: 0 }
+    }
+
+    /// Returns one (multiplicative identity)
+    #[inline]
+    pub const fn one() -> Self {
+        Self {
+            value: Self::CTX.one(),
+        }
+    }
+
+    /// Creates a new element of `ℤ/MOD ℤ`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `value`: One of element in `ℤ`.
+    ///
+    /// ## Returns
+    ///
+    /// `value % MOD` becomes representative.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Self {
+            value: Self::CTX.to_mont(value),
+        }
+    }
+
+    /// Returns the representative in `0..MOD`.
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        Self::CTX.from_mont(self.value)
+    }
+
+    /// Returns `self^n` which is an element of `ℤ/MOD ℤ`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n`: The exponent.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log n)`
+    #[inline]
+    pub fn pow(&self, n: u64) -> Self {
+        Self {
+            value: Self::CTX.pow(self.value, n),
+        }
+    }
+
+    /// Returns `self^{-1}` which is the inverse of `self` in `ℤ/MOD ℤ`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `self == 0` (zero has no multiplicative inverse)
+    ///
+    /// ## Complexity
+    ///
+    /// `O(log MOD)`
+    #[inline]
+    pub fn inv(&self) -> Self {
+        debug_assert_ne!(
+            self.value, 0,
+            "The value which you want to calculate inverse must not be 0"
+        );
+        // Uses Fermat's Little Theorem: a^(MOD-2) ≡ a^(-1).
+        self.pow(MOD - 2)
+    }
+}
+
+use std::fmt::{Debug, Display, Formatter, Result};
+impl<const MOD: u64> Debug for GF64<MOD> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value())
+    }
+}
+impl<const MOD: u64> Display for GF64<MOD> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+impl<const MOD: u64> Neg for GF64<MOD> {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        self.value = Self::CTX.sub(0, self.value);
+        self
+    }
+}
+impl<const MOD: u64> AddAssign<GF64<MOD>> for GF64<MOD> {
+    fn add_assign(&mut self, rhs: GF64<MOD>) {
+        self.value = Self::CTX.add(self.value, rhs.value);
+    }
+}
+impl<const MOD: u64> SubAssign<GF64<MOD>> for GF64<MOD> {
+    fn sub_assign(&mut self, rhs: GF64<MOD>) {
+        self.value = Self::CTX.sub(self.value, rhs.value);
+    }
+}
+impl<const MOD: u64> MulAssign<GF64<MOD>> for GF64<MOD> {
+    fn mul_assign(&mut self, rhs: GF64<MOD>) {
+        self.value = Self::CTX.mul(self.value, rhs.value);
+    }
+}
+impl<const MOD: u64> DivAssign<GF64<MOD>> for GF64<MOD> {
+    fn div_assign(&mut self, rhs: GF64<MOD>) {
+        self.value = Self::CTX.mul(self.value, rhs.inv().value);
+    }
+}
+
+macro_rules! gf64_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl<const MOD: u64> $trait_assign<&GF64<MOD>> for GF64<MOD> {
+            fn $fn_assign(&mut self, rhs: &GF64<MOD>) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<const MOD: u64, T: Into<GF64<MOD>>> $trait<T> for GF64<MOD> {
+            type Output = GF64<MOD>;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl<const MOD: u64> $trait<&GF64<MOD>> for GF64<MOD> {
+            type Output = GF64<MOD>;
+            fn $fn(self, rhs: &GF64<MOD>) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<const MOD: u64, T: Into<GF64<MOD>>> $trait<T> for &GF64<MOD> {
+            type Output = GF64<MOD>;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl<const MOD: u64> $trait<&GF64<MOD>> for &GF64<MOD> {
+            type Output = GF64<MOD>;
+            fn $fn(self, rhs: &GF64<MOD>) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+
+gf64_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+
+use std::iter::{Product, Sum};
+impl<const MOD: u64> Sum for GF64<MOD> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, a| acc + a)
+    }
+}
+impl<'a, const MOD: u64> Sum<&'a Self> for GF64<MOD> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+impl<const MOD: u64> Product for GF64<MOD> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, a| acc * a)
+    }
+}
+impl<'a, const MOD: u64> Product<&'a Self> for GF64<MOD> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+macro_rules! gf64_new_from_signed {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u64> From<$t> for GF64<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new(
+                        (x as i128).rem_euclid(MOD as i128) as u64
+                    )
+                }
+            }
+        )*
+    };
+}
+gf64_new_from_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! gf64_new_from_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u64> From<$t> for GF64<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as u128 % MOD as u128) as u64)
+                }
+            }
+        )*
+    };
+}
+gf64_new_from_unsigned!(u8, u16, u32, u64, u128, usize);
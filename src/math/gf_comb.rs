@@ -10,6 +10,10 @@
 //! - [`Comb::perm`]: Calculate permutation `P(n, r)`
 //! - [`Comb::multinom`]: Calculate multinomial coefficient
 //! - [`Comb::homogeneous`]: Calculate homogeneous product (stars and bars)
+//!
+//! [`GrowableComb`] provides the same numbers without a fixed table size, together with
+//! binomial coefficients `C(n, r)` for negative `n`. [`QComb`] provides the q-analogues
+//! `[n]_q`, `[n]_q!` and the q-binomial coefficients.
 
 use super::gf::GF;
 
@@ -123,10 +127,11 @@ impl<const MOD: u32> Comb<MOD> {
     ///
     /// `C(n, r) = n! / (r! * (n-r)!) = n choose r`
     ///
+    /// and `C(n, r) = 0` for `r > n`.
+    ///
     /// ## Panics
     ///
-    /// - Panics if `n >= self.n`.
-    /// - Panics if `r > n`.
+    /// Panics if `n >= self.n`.
     ///
     /// ## Complexity
     ///
@@ -141,14 +146,9 @@ impl<const MOD: u32> Comb<MOD> {
             self.n,
             n
         );
-        debug_assert!(
-            r <= n,
-            "binom({}, {}) requires r <= n (got n = {}, r = {})",
-            n,
-            r,
-            n,
-            r
-        );
+        if r > n {
+            return GF::zero();
+        }
         self.fact[n as usize] * self.inv_fact[r as usize] * self.inv_fact[(n - r) as usize]
     }
 
@@ -160,10 +160,11 @@ impl<const MOD: u32> Comb<MOD> {
     ///
     /// `P(n, r) = n! / (n-r)! = n * (n - 1) * ... * (n-r+1)`
     ///
+    /// and `P(n, r) = 0` for `r > n`.
+    ///
     /// ## Panics
     ///
-    /// - Panics if `n >= self.n`.
-    /// - Panics if `r > n`.
+    /// Panics if `n >= self.n`.
     ///
     /// ## Complexity
     ///
@@ -178,14 +179,9 @@ impl<const MOD: u32> Comb<MOD> {
             self.n,
             n
         );
-        debug_assert!(
-            r <= n,
-            "perm({}, {}) requires r <= n (got n = {}, r = {})",
-            n,
-            r,
-            n,
-            r
-        );
+        if r > n {
+            return GF::zero();
+        }
         self.fact[n as usize] * self.inv_fact[(n - r) as usize]
     }
 
@@ -268,3 +264,409 @@ impl<const MOD: u32> Comb<MOD> {
         self.binom(n + r - 1, r - 1)
     }
 }
+
+/// A structure for combinatorial calculations modulo a prime whose tables grow on demand.
+///
+/// Unlike [`Comb`], no size is fixed up front: a query beyond the current tables extends them
+/// to at least twice their size, so each entry costs amortised `O(1)`.
+///
+/// # Type Parameters
+///
+/// - `MOD`: The prime modulus
+///   (satisfy `MOD` is prime)
+///
+/// # Requirements
+///
+/// **`MOD` must be prime**, and every factorial index must be smaller than `MOD`.
+///
+/// # Examples
+///
+/// ```
+/// use math_optim::math::gf_comb::GrowableComb;
+///
+/// let mut comb = GrowableComb::<998244353>::new();
+/// // The tables start with `0!` only and grow as needed.
+/// assert_eq!(comb.factorial(10).value(), 3628800);
+/// assert_eq!(comb.binom(1000, 3).value(), 166167000);
+/// assert_eq!(comb.factorial(20).value(), (2432902008176640000u64 % 998244353) as u32);
+///
+/// // C(-3, 2) = (-3) (-4) / 2! = 6 and C(-1, r) = (-1)^r.
+/// assert_eq!(comb.binom(-3, 2).value(), 6);
+/// assert_eq!(comb.binom(-1, 5).value(), 998244352);
+/// // Out-of-range `r` gives 0 instead of panicking.
+/// assert_eq!(comb.binom(3, 5).value(), 0);
+/// assert_eq!(comb.binom(3, -1).value(), 0);
+/// assert_eq!(comb.perm(3, 5).value(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GrowableComb<const MOD: u32> {
+    /// Precomputed factorials: `fact[i] = i! mod MOD`
+    fact: Vec<GF<MOD>>,
+
+    /// Precomputed inverse factorials: `inv_fact[i] = (i!)^(-1) mod MOD`
+    inv_fact: Vec<GF<MOD>>,
+
+    /// Precomputed inverses: `inv[i] = i^(-1) mod MOD` (with `inv[0] = 0`)
+    inv: Vec<GF<MOD>>,
+}
+
+impl<const MOD: u32> Default for GrowableComb<MOD> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MOD: u32> GrowableComb<MOD> {
+    /// Creates a new `GrowableComb` holding only `0!`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn new() -> Self {
+        Self {
+            fact: vec![GF::one()],
+            inv_fact: vec![GF::one()],
+            inv: vec![GF::zero()],
+        }
+    }
+
+    /// Extends the tables so that they cover `0, 1, ..., n`.
+    ///
+    /// The tables grow to at least twice their current size (capped at `MOD`).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= MOD`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)` in the worst case, amortised `O(1)` per entry
+    pub fn reserve(&mut self, n: u32) {
+        let len = self.fact.len();
+        if (n as usize) < len {
+            return;
+        }
+        debug_assert!(
+            n < MOD,
+            "reserve({}) requires n < {} (got n = {})",
+            n,
+            MOD,
+            n
+        );
+        let new_len = (n as usize + 1).max(2 * len).min(MOD as usize);
+        for i in len..new_len {
+            // i^(-1) = -(MOD / i) (MOD % i)^(-1), where MOD % i < i is already known.
+            let inv = if i == 1 {
+                GF::one()
+            } else {
+                -self.inv[MOD as usize % i] * GF::new(MOD / i as u32)
+            };
+            self.fact.push(self.fact[i - 1] * GF::new(i as u32));
+            self.inv_fact.push(self.inv_fact[i - 1] * inv);
+            self.inv.push(inv);
+        }
+    }
+
+    /// Returns the factorial `n!` modulo `MOD`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= MOD`.
+    ///
+    /// ## Complexity
+    ///
+    /// Amortised `O(1)`
+    #[inline]
+    pub fn factorial(&mut self, n: u32) -> GF<MOD> {
+        self.reserve(n);
+        self.fact[n as usize]
+    }
+
+    /// Returns the inverse factorial `(n!)^(-1)` modulo `MOD`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= MOD`.
+    ///
+    /// ## Complexity
+    ///
+    /// Amortised `O(1)`
+    #[inline]
+    pub fn inv_factorial(&mut self, n: u32) -> GF<MOD> {
+        self.reserve(n);
+        self.inv_fact[n as usize]
+    }
+
+    /// Returns the inverse `n^(-1)` modulo `MOD`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n == 0` or `n >= MOD`.
+    ///
+    /// ## Complexity
+    ///
+    /// Amortised `O(1)`
+    #[inline]
+    pub fn inv(&mut self, n: u32) -> GF<MOD> {
+        debug_assert_ne!(n, 0, "inv({}) requires n > 0", n);
+        self.reserve(n);
+        self.inv[n as usize]
+    }
+
+    /// Returns the generalized binomial coefficient `C(n, r)` modulo `MOD`.
+    ///
+    /// ## Definition
+    ///
+    /// For an integer `n` (possibly negative) and an integer `r`:
+    ///
+    /// `C(n, r) = n * (n - 1) * ... * (n-r+1) / r!` for `r >= 0`, and `C(n, r) = 0` for `r < 0`.
+    ///
+    /// For `n >= 0` this is the usual binomial coefficient (`0` if `r > n`), and for `n < 0`,
+    ///
+    /// `C(n, r) = (-1)^r C(r - n - 1, r)`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= MOD` for `n >= 0`, or `r - n - 1 >= MOD` for `n < 0`.
+    ///
+    /// ## Complexity
+    ///
+    /// Amortised `O(1)`
+    pub fn binom(&mut self, n: i64, r: i64) -> GF<MOD> {
+        if r < 0 {
+            return GF::zero();
+        }
+        if n < 0 {
+            let res = self.binom(r - n - 1, r);
+            return if r % 2 == 0 { res } else { -res };
+        }
+        if r > n {
+            return GF::zero();
+        }
+        debug_assert!(
+            n < MOD as i64,
+            "binom({}, {}) requires n < {} (got n = {})",
+            n,
+            r,
+            MOD,
+            n
+        );
+        self.reserve(n as u32);
+        self.fact[n as usize] * self.inv_fact[r as usize] * self.inv_fact[(n - r) as usize]
+    }
+
+    /// Returns the permutation `P(n, r) = n! / (n-r)!` modulo `MOD` (`0` if `r > n`).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= MOD`.
+    ///
+    /// ## Complexity
+    ///
+    /// Amortised `O(1)`
+    pub fn perm(&mut self, n: u32, r: u32) -> GF<MOD> {
+        if r > n {
+            return GF::zero();
+        }
+        self.reserve(n);
+        self.fact[n as usize] * self.inv_fact[(n - r) as usize]
+    }
+}
+
+/// A structure for q-analogues of combinatorial numbers modulo a prime.
+///
+/// Precomputes `[k]_q` and `[k]_q!` for `0 <= k < n`, where
+///
+/// - `[k]_q = 1 + q + q^2 + ... + q^(k-1)` (the q-number),
+/// - `[k]_q! = [1]_q [2]_q ... [k]_q` (the q-factorial),
+/// - `C_q(n, r) = [n]_q! / ([r]_q! [n-r]_q!)` (the q-binomial coefficient).
+///
+/// If `q != 1` has multiplicative order `d`, then `[d]_q = 0` (and `[p]_1 = 0` for `q = 1`), so
+/// q-binomials are computed by the q-Lucas theorem instead of the quotient:
+///
+/// `C_q(n, r) = C(n / d, r / d) C_q(n % d, r % d)`.
+///
+/// # Type Parameters
+///
+/// - `MOD`: The prime modulus
+///   (satisfy `MOD` is prime)
+///
+/// # Requirements
+///
+/// **`MOD` must be prime.**
+///
+/// # Examples
+///
+/// At a primitive 4th root of unity `q`, `[4]_q = 0`, so the q-binomials come from the q-Lucas
+/// theorem. They agree with the recurrence `C_q(n, r) = C_q(n-1, r-1) + q^r C_q(n-1, r)`:
+///
+/// ```
+/// use math_optim::math::gf::GF;
+/// use math_optim::math::gf_comb::QComb;
+///
+/// type Mint = GF<998244353>;
+///
+/// let q = Mint::new(3).pow((998244353 - 1) / 4);
+/// assert_eq!(q.pow(4), Mint::one());
+/// let comb = QComb::build(q, 20);
+/// assert_eq!(comb.q_number(4), Mint::zero());
+/// assert_eq!(comb.q_factorial(5), Mint::zero());
+///
+/// let mut row = vec![Mint::one()];
+/// for n in 1..20 {
+///     let mut next = vec![Mint::one(); n + 1];
+///     for r in 1..n {
+///         next[r] = row[r - 1] + q.pow(r as u32) * row[r];
+///     }
+///     row = next;
+///     for r in 0..=n {
+///         assert_eq!(comb.q_binom(n as u32, r as u32), row[r]);
+///     }
+/// }
+/// // C_q(8, 4) = C(2, 1) C_q(0, 0)
+/// assert_eq!(comb.q_binom(8, 4), Mint::new(2));
+/// ```
+#[derive(Clone)]
+pub struct QComb<const MOD: u32> {
+    /// Maximum value for which the tables are precomputed (exclusive upper bound)
+    n: u32,
+
+    /// The smallest `d >= 1` with `[d]_q = 0`, or `n` if there is none below `n`
+    period: u32,
+
+    /// Precomputed q-numbers: `num[i] = [i]_q mod MOD`
+    num: Vec<GF<MOD>>,
+
+    /// Precomputed q-factorials: `fact[i] = [i]_q! mod MOD`
+    fact: Vec<GF<MOD>>,
+
+    /// Precomputed inverse q-factorials: `inv_fact[i] = ([i]_q!)^(-1) mod MOD` for `i < period`
+    inv_fact: Vec<GF<MOD>>,
+
+    /// Ordinary binomial coefficients for the quotients by `period`
+    comb: Comb<MOD>,
+}
+
+impl<const MOD: u32> QComb<MOD> {
+    /// Creates a new `QComb` structure for `q` with tables up to `n - 1`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `q`: The parameter of the q-analogues
+    /// - `n`: Precompute `[0]_q!` to `[n-1]_q!` (must satisfy `1 <= n <= MOD`)
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n == 0` or `n > MOD`
+    pub fn build(q: GF<MOD>, n: u32) -> Self {
+        debug_assert!(n > 0, "n must be at least 1 (got n = {})", n);
+        debug_assert!(n <= MOD, "n must be at most {} (got n = {})", MOD, n);
+        let mut num = Vec::with_capacity(n as usize);
+        num.push(GF::zero());
+        for i in 1..n as usize {
+            num.push(num[i - 1] * q + GF::one());
+        }
+        let mut fact = Vec::with_capacity(n as usize);
+        fact.push(GF::one());
+        for i in 1..n as usize {
+            fact.push(fact[i - 1] * num[i]);
+        }
+        let period = (1..n).find(|&i| num[i as usize] == GF::zero()).unwrap_or(n);
+        let mut inv_fact = Vec::with_capacity(period as usize);
+        inv_fact.push(fact[period as usize - 1].inv());
+        for i in (1..period as usize).rev() {
+            inv_fact.push(inv_fact.last().unwrap() * num[i]);
+        }
+        inv_fact.reverse();
+        let comb = Comb::build((n - 1) / period + 1);
+        Self {
+            n,
+            period,
+            num,
+            fact,
+            inv_fact,
+            comb,
+        }
+    }
+
+    /// Returns the q-number `[n]_q = 1 + q + ... + q^(n-1)` modulo `MOD`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= self.n` (value not precomputed).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn q_number(&self, n: u32) -> GF<MOD> {
+        debug_assert!(
+            n < self.n,
+            "q_number({}) is not precomputed (max: {})",
+            n,
+            self.n - 1
+        );
+        self.num[n as usize]
+    }
+
+    /// Returns the q-factorial `[n]_q! = [1]_q [2]_q ... [n]_q` modulo `MOD`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= self.n` (value not precomputed).
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    #[inline]
+    pub fn q_factorial(&self, n: u32) -> GF<MOD> {
+        debug_assert!(
+            n < self.n,
+            "q_factorial({}) is not precomputed (max: {})",
+            n,
+            self.n - 1
+        );
+        self.fact[n as usize]
+    }
+
+    /// Returns the q-binomial coefficient `C_q(n, r)` modulo `MOD`.
+    ///
+    /// ## Definition
+    ///
+    /// For non-negative integers `n` and `r` with `r <= n`:
+    ///
+    /// `C_q(n, r) = [n]_q! / ([r]_q! [n-r]_q!)`
+    ///
+    /// as a polynomial in `q`, and `C_q(n, r) = 0` for `r > n`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n >= self.n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn q_binom(&self, n: u32, r: u32) -> GF<MOD> {
+        debug_assert!(
+            n < self.n,
+            "q_binom({}, {}) requires n < {} (got n = {})",
+            n,
+            r,
+            self.n,
+            n
+        );
+        if r > n {
+            return GF::zero();
+        }
+        let d = self.period;
+        let (n0, r0) = ((n % d) as usize, (r % d) as usize);
+        if r0 > n0 {
+            return GF::zero();
+        }
+        self.comb.binom(n / d, r / d) * self.fact[n0] * self.inv_fact[r0] * self.inv_fact[n0 - r0]
+    }
+}
@@ -0,0 +1,144 @@
+//! Lagrange interpolation at consecutive points
+//!
+//! Evaluation of a polynomial given by its values at `0, 1, ..., n-1`, using the factorials
+//! precomputed by [`Comb`], and power sums built on top of it.
+//!
+//! # Available operations
+//!
+//! - [`Comb::lagrange_consecutive`]: Evaluate `f(x)` from `f(0), f(1), ..., f(n-1)`
+//! - [`Comb::sum_of_powers`]: Calculate `sum_(i<N) i^k`
+//! - [`Comb::sum_of_exponential_powers`]: Calculate `sum_(i<N) a^i i^k`
+
+use super::gf::GF;
+use super::gf_comb::Comb;
+
+impl<const MOD: u32> Comb<MOD> {
+    /// Returns `f(x)` for the polynomial `f` of degree `< n` with `f(i) = ys[i]` for `0 <= i < n`.
+    ///
+    /// ## Definition
+    ///
+    /// `f(x) = sum_i ys[i] * prod_(j != i) (x - j) / (i - j)`
+    ///
+    /// where `prod_(j != i) (i - j) = (-1)^(n-1-i) i! (n-1-i)!`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `ys`: Values at `0, 1, ..., n-1` (satisfy `ys.len() <= self.n`)
+    /// - `x`: The point to evaluate at (any `u64`)
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)` where `n = ys.len()`
+    pub fn lagrange_consecutive(&self, ys: &[GF<MOD>], x: u64) -> GF<MOD> {
+        let n = ys.len();
+        if (x as u128) < n as u128 {
+            return ys[x as usize];
+        }
+        let x = GF::<MOD>::new((x % MOD as u64) as u32);
+        // prefix[i] = prod_(j < i) (x - j), suffix[i] = prod_(j >= i) (x - j)
+        let mut prefix = vec![GF::one(); n + 1];
+        for i in 0..n {
+            prefix[i + 1] = prefix[i] * (x - GF::new(i as u32));
+        }
+        let mut suffix = vec![GF::one(); n + 1];
+        for i in (0..n).rev() {
+            suffix[i] = suffix[i + 1] * (x - GF::new(i as u32));
+        }
+        ys.iter()
+            .enumerate()
+            .map(|(i, y)| {
+                let term = y
+                    * prefix[i]
+                    * suffix[i + 1]
+                    * self.inv_factorial(i as u32)
+                    * self.inv_factorial((n - 1 - i) as u32);
+                if (n - 1 - i) % 2 == 0 { term } else { -term }
+            })
+            .sum()
+    }
+
+    /// Returns `sum_(i<n) i^k`, with the convention `0^0 = 1`.
+    ///
+    /// The sum is a polynomial in `n` of degree `k + 1`, so it is interpolated from its values
+    /// at `0, 1, ..., k+1`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `k + 2 > self.n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(k log k)`
+    pub fn sum_of_powers(&self, k: u32, n: u64) -> GF<MOD> {
+        let mut ys = Vec::with_capacity(k as usize + 2);
+        ys.push(GF::zero());
+        for i in 0..=k {
+            ys.push(ys[i as usize] + GF::new(i).pow(k));
+        }
+        self.lagrange_consecutive(&ys, n)
+    }
+
+    /// Returns `sum_(i<n) a^i i^k`, with the convention `0^0 = 1`.
+    ///
+    /// For `a != 1`, the sum equals `a^n g(n) - g(0)` for a polynomial `g` of degree `<= k`.
+    /// `g(0)` is determined by the vanishing `(k+1)`-th finite difference of `g`, and `g(n)` is
+    /// interpolated from `g(0), g(1), ..., g(k)`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `k + 2 > self.n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(k log k)`
+    pub fn sum_of_exponential_powers(&self, a: GF<MOD>, k: u32, n: u64) -> GF<MOD> {
+        if a == GF::one() {
+            return self.sum_of_powers(k, n);
+        }
+        if a == GF::zero() {
+            // Only the term `0^0 0^k` can be non-zero.
+            return if k == 0 && n > 0 {
+                GF::one()
+            } else {
+                GF::zero()
+            };
+        }
+        // s[i] = sum_(j<i) a^j j^k for 0 <= i <= k+1
+        let m = k as usize + 2;
+        let mut s = Vec::with_capacity(m);
+        s.push(GF::zero());
+        let mut a_pow = GF::one();
+        for i in 0..m as u32 - 1 {
+            s.push(s[i as usize] + a_pow * GF::new(i).pow(k));
+            a_pow *= a;
+        }
+        if (n as u128) < m as u128 {
+            return s[n as usize];
+        }
+
+        // g(i) = (s[i] + g(0)) a^(-i) and sum_i (-1)^(k+1-i) C(k+1, i) g(i) = 0.
+        let a_inv = a.inv();
+        let mut num = GF::zero();
+        let mut den = GF::zero();
+        let mut a_inv_pow = GF::one();
+        for (i, s) in s.iter().enumerate() {
+            let mut w = self.binom(k + 1, i as u32) * a_inv_pow;
+            if (m - 1 - i) % 2 == 1 {
+                w = -w;
+            }
+            num += w * s;
+            den += w;
+            a_inv_pow *= a_inv;
+        }
+        let g0 = -num / den;
+
+        let mut g = Vec::with_capacity(m - 1);
+        let mut a_inv_pow = GF::one();
+        for s in &s[..m - 1] {
+            g.push((s + g0) * a_inv_pow);
+            a_inv_pow *= a_inv;
+        }
+        let a_n = a.pow((n % (MOD as u64 - 1)) as u32);
+        a_n * self.lagrange_consecutive(&g, n) - g0
+    }
+}
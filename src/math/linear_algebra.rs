@@ -0,0 +1,278 @@
+//! Linear algebra over `GF<MOD>`
+//!
+//! Gaussian elimination and related algorithms for [`Matrix<GF<MOD>>`](Matrix).
+//!
+//! # Available operations
+//!
+//! - [`Matrix::rref`]: Reduced row echelon form with pivot columns
+//! - [`Matrix::rank`]: Rank of a matrix
+//! - [`Matrix::det`]: Determinant of a square matrix
+//! - [`Matrix::inv`]: Inverse of a square matrix
+//! - [`Matrix::solve`]: Solution set of `Ax = b` as a particular solution and a kernel basis
+//! - [`Matrix::char_poly`]: Characteristic polynomial via the Hessenberg form
+
+use super::gf::GF;
+use super::matrix::Matrix;
+
+/// Transforms `a` into reduced row echelon form, pivoting only on the first `limit` columns.
+///
+/// Returns the pivot columns and the determinant factor: the product of the pivots, negated for
+/// each row swap. For a square full-rank `a` the factor is `det(a)`.
+fn eliminate<const MOD: u32>(a: &mut [Vec<GF<MOD>>], limit: usize) -> (Vec<usize>, GF<MOD>) {
+    let h = a.len();
+    let mut pivots = Vec::new();
+    let mut factor = GF::one();
+    for col in 0..limit {
+        let r = pivots.len();
+        if r == h {
+            break;
+        }
+        let Some(p) = (r..h).find(|&i| a[i][col] != GF::zero()) else {
+            continue;
+        };
+        if p != r {
+            a.swap(p, r);
+            factor = -factor;
+        }
+        let pivot = a[r][col];
+        factor *= pivot;
+        let inv = pivot.inv();
+        for x in &mut a[r][col..] {
+            *x *= inv;
+        }
+        let (above, rest) = a.split_at_mut(r);
+        let (row, below) = rest.split_first_mut().unwrap();
+        for other in above.iter_mut().chain(below) {
+            let c = other[col];
+            if c == GF::zero() {
+                continue;
+            }
+            for (x, y) in other[col..].iter_mut().zip(&row[col..]) {
+                *x -= c * y;
+            }
+        }
+        pivots.push(col);
+    }
+    (pivots, factor)
+}
+
+impl<const MOD: u32> Matrix<GF<MOD>> {
+    /// Returns the rows of the matrix as vectors.
+    fn to_rows(&self) -> Vec<Vec<GF<MOD>>> {
+        (0..self.height()).map(|i| self.row(i).to_vec()).collect()
+    }
+
+    /// Returns the reduced row echelon form of the matrix.
+    ///
+    /// ## Returns
+    ///
+    /// A pair `(r, pivots)`, where `r` is the reduced row echelon form and `pivots[i]` is the
+    /// column of the leading `1` of the `i`-th row of `r`. The length of `pivots` is the rank.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw min(h, w))`
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut a = self.to_rows();
+        let (pivots, _) = eliminate(&mut a, self.width());
+        (Self::from_rows_with_width(a, self.width()), pivots)
+    }
+
+    /// Returns the rank of the matrix.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw min(h, w))`
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// Returns the determinant of the matrix.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n^3)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn det(&self) -> GF<MOD> {
+        let n = self.height();
+        assert_eq!(n, self.width(), "matrix must be square");
+        let mut a = self.to_rows();
+        let (pivots, factor) = eliminate(&mut a, n);
+        if pivots.len() == n {
+            factor
+        } else {
+            GF::zero()
+        }
+    }
+
+    /// Returns the inverse of the matrix.
+    ///
+    /// ## Returns
+    ///
+    /// `Some(inverse)` if the matrix is regular, `None` otherwise.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n^3)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn inv(&self) -> Option<Self> {
+        let n = self.height();
+        assert_eq!(n, self.width(), "matrix must be square");
+        let mut a: Vec<_> = (0..n)
+            .map(|i| {
+                let mut row = self.row(i).to_vec();
+                row.resize(2 * n, GF::zero());
+                row[n + i] = GF::one();
+                row
+            })
+            .collect();
+        let (pivots, _) = eliminate(&mut a, n);
+        if pivots.len() < n {
+            return None;
+        }
+        Some(Self::from_rows_with_width(
+            a.into_iter().map(|row| row[n..].to_vec()).collect(),
+            n,
+        ))
+    }
+
+    /// Solves the linear system `Ax = b`, where `A` is this matrix.
+    ///
+    /// ## Parameters
+    ///
+    /// - `b`: The right-hand side (must satisfy `b.len() == self.height()`)
+    ///
+    /// ## Returns
+    ///
+    /// `None` if the system has no solution. Otherwise `Some((x, kernel))`, where `x` is a
+    /// solution and `kernel` is a basis of `{ y | Ay = 0 }`, so that the solutions are exactly
+    /// `x + Σ c_i kernel[i]`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw min(h, w))`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `b.len() != self.height()`.
+    pub fn solve(&self, b: &[GF<MOD>]) -> Option<(Vec<GF<MOD>>, Vec<Vec<GF<MOD>>>)> {
+        let (h, w) = (self.height(), self.width());
+        assert_eq!(b.len(), h, "dimension mismatch");
+        let mut a: Vec<_> = (0..h)
+            .map(|i| {
+                let mut row = self.row(i).to_vec();
+                row.push(b[i]);
+                row
+            })
+            .collect();
+        let (pivots, _) = eliminate(&mut a, w);
+        if a[pivots.len()..].iter().any(|row| row[w] != GF::zero()) {
+            return None;
+        }
+        let mut x = vec![GF::zero(); w];
+        for (row, &p) in a.iter().zip(&pivots) {
+            x[p] = row[w];
+        }
+        let mut is_pivot = vec![false; w];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let kernel = (0..w)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut y = vec![GF::zero(); w];
+                y[f] = GF::one();
+                for (row, &p) in a.iter().zip(&pivots) {
+                    y[p] = -row[f];
+                }
+                y
+            })
+            .collect();
+        Some((x, kernel))
+    }
+
+    /// Returns the characteristic polynomial `det(xI - A)` of the matrix.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form by similarity transformations.
+    ///
+    /// ## Returns
+    ///
+    /// The coefficients `p` of the monic polynomial of degree `n` in ascending order, i.e.
+    /// `det(xI - A) = p[0] + p[1] x + ... + p[n] x^n`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n^3)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn char_poly(&self) -> Vec<GF<MOD>> {
+        let n = self.height();
+        assert_eq!(n, self.width(), "matrix must be square");
+        let mut a = self.to_rows();
+
+        // Hessenberg reduction: eliminate entries below the subdiagonal.
+        for j in 0..n.saturating_sub(2) {
+            let Some(p) = (j + 1..n).find(|&i| a[i][j] != GF::zero()) else {
+                continue;
+            };
+            if p != j + 1 {
+                a.swap(p, j + 1);
+                for row in a.iter_mut() {
+                    row.swap(p, j + 1);
+                }
+            }
+            let inv = a[j + 1][j].inv();
+            for k in j + 2..n {
+                let c = a[k][j] * inv;
+                if c == GF::zero() {
+                    continue;
+                }
+                let (upper, lower) = a.split_at_mut(k);
+                for (x, y) in lower[0][j..].iter_mut().zip(&upper[j + 1][j..]) {
+                    *x -= c * y;
+                }
+                for row in a.iter_mut() {
+                    let v = row[k];
+                    row[j + 1] += c * v;
+                }
+            }
+        }
+
+        // p[i] is the characteristic polynomial of the leading i × i principal submatrix.
+        let mut p = vec![vec![GF::one()]];
+        for i in 0..n {
+            let mut next = vec![GF::zero(); i + 2];
+            for (k, &c) in p[i].iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= c * a[i][i];
+            }
+            let mut t = GF::one();
+            for j in (0..i).rev() {
+                t *= a[j + 1][j];
+                let c = t * a[j][i];
+                for (k, &d) in p[j].iter().enumerate() {
+                    next[k] -= c * d;
+                }
+            }
+            p.push(next);
+        }
+        p.pop().unwrap()
+    }
+
+    /// Creates a matrix from rows, keeping the width `w` even if there are no rows.
+    fn from_rows_with_width(rows: Vec<Vec<GF<MOD>>>, w: usize) -> Self {
+        if rows.is_empty() {
+            Self::zero(0, w)
+        } else {
+            Self::from_rows(rows)
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Linear algebra over `GF(2)`
+//!
+//! Gaussian elimination on matrices whose rows are [`Bitset`]s, so that adding one row to
+//! another takes `O(w / 64)` time.
+//!
+//! # Available functions
+//!
+//! - [`eliminate`]: Transform rows into reduced row echelon form in place
+//! - [`rank`]: Rank of a matrix
+//! - [`solve`]: Solution set of `Ax = b` as a particular solution and a kernel basis
+
+use crate::ds::bitset::Bitset;
+
+/// Transforms the rows `a` into reduced row echelon form in place, pivoting only on the first
+/// `limit` columns.
+///
+/// ## Parameters
+///
+/// - `a`: The rows of the matrix (all must have the same length)
+/// - `limit`: The number of columns to pivot on (must satisfy `limit <= a[i].len()`)
+///
+/// ## Returns
+///
+/// The pivot columns: `pivots[i]` is the column of the leading `1` of row `i` after elimination.
+///
+/// ## Complexity
+///
+/// `O(h w min(h, w) / 64)` for `h` rows of length `w`
+pub fn eliminate(a: &mut [Bitset], limit: usize) -> Vec<usize> {
+    let h = a.len();
+    let mut pivots = Vec::new();
+    for col in 0..limit {
+        let r = pivots.len();
+        if r == h {
+            break;
+        }
+        let Some(p) = (r..h).find(|&i| a[i].get(col)) else {
+            continue;
+        };
+        a.swap(p, r);
+        let (above, rest) = a.split_at_mut(r);
+        let (row, below) = rest.split_first_mut().unwrap();
+        for other in above.iter_mut().chain(below) {
+            if other.get(col) {
+                *other ^= &*row;
+            }
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+/// Returns the rank of the matrix with rows `a`.
+///
+/// ## Complexity
+///
+/// `O(h w min(h, w) / 64)` for `h` rows of length `w`
+pub fn rank(a: &[Bitset]) -> usize {
+    let w = a.first().map_or(0, Bitset::len);
+    eliminate(&mut a.to_vec(), w).len()
+}
+
+/// Solves the linear system `Ax = b` over `GF(2)`.
+///
+/// ## Parameters
+///
+/// - `a`: The rows of `A`, each of length `w`
+/// - `w`: The number of columns (needed when `a` is empty)
+/// - `b`: The right-hand side (must satisfy `b.len() == a.len()`)
+///
+/// ## Returns
+///
+/// `None` if the system has no solution. Otherwise `Some((x, kernel))`, where `x` is a solution
+/// and `kernel` is a basis of `{ y | Ay = 0 }`, so that the solutions are exactly
+/// `x ^ (xor of a subset of kernel)`.
+///
+/// ## Complexity
+///
+/// `O(h w min(h, w) / 64)` for `h` rows
+///
+/// ## Panics
+///
+/// Panics if `b.len() != a.len()`.
+pub fn solve(a: &[Bitset], w: usize, b: &[bool]) -> Option<(Bitset, Vec<Bitset>)> {
+    assert_eq!(b.len(), a.len(), "dimension mismatch");
+    let mut aug: Vec<Bitset> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &bi)| {
+            debug_assert_eq!(row.len(), w, "length mismatch: {} and {}", row.len(), w);
+            let mut r = Bitset::new(w + 1);
+            for j in row.ones() {
+                r.set(j);
+            }
+            if bi {
+                r.set(w);
+            }
+            r
+        })
+        .collect();
+    let pivots = eliminate(&mut aug, w);
+    if aug[pivots.len()..].iter().any(|row| row.get(w)) {
+        return None;
+    }
+    let mut x = Bitset::new(w);
+    for (row, &p) in aug.iter().zip(&pivots) {
+        if row.get(w) {
+            x.set(p);
+        }
+    }
+    let mut is_pivot = vec![false; w];
+    for &p in &pivots {
+        is_pivot[p] = true;
+    }
+    let kernel = (0..w)
+        .filter(|&f| !is_pivot[f])
+        .map(|f| {
+            let mut y = Bitset::new(w);
+            y.set(f);
+            for (row, &p) in aug.iter().zip(&pivots) {
+                if row.get(f) {
+                    y.set(p);
+                }
+            }
+            y
+        })
+        .collect();
+    Some((x, kernel))
+}
@@ -0,0 +1,143 @@
+//! Linear recurrence
+//!
+//! Functions for sequences satisfying a linear recurrence over `GF<MOD>`:
+//!
+//! `a_i = c_1 a_(i-1) + c_2 a_(i-2) + ... + c_d a_(i-d)` for all `i >= d`.
+//!
+//! Such a sequence is the coefficient sequence of a rational function `P(x) / Q(x)` with
+//! `Q(x) = 1 - c_1 x - c_2 x^2 - ... - c_d x^d` and `deg P < d`.
+//!
+//! # Available functions
+//!
+//! - [`berlekamp_massey`]: Find the shortest linear recurrence of a sequence
+//! - [`coeff_of_rational`]: Calculate `[x^n] P(x) / Q(x)` (Bostan-Mori algorithm)
+//! - [`nth_term`]: Calculate the `n`-th term of a linearly recurrent sequence
+
+use super::conv::ntt::ntt_conv;
+use super::gf::GF;
+
+/// Returns the coefficients `[c_1, c_2, ..., c_d]` of the shortest linear recurrence satisfied
+/// by `a`.
+///
+/// ## Definition
+///
+/// The returned `c` satisfies `a_i = sum_j c_j a_(i-j)` for all `d <= i < a.len()`, and `d` is
+/// the minimum. If `a` has length `>= 2d` for the true minimal recurrence of an infinite
+/// sequence, the recurrence is recovered exactly.
+///
+/// ## Complexity
+///
+/// `O(n^2)` where `n = a.len()`
+pub fn berlekamp_massey<const MOD: u32>(a: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    // Connection polynomials `C(x) = 1 + cur[0] x + cur[1] x^2 + ...` (so `cur[j] = -c_(j+1)`),
+    // and `B(x) = 1 + prev[0] x + ...`, the last `C` before the length changed.
+    let mut cur: Vec<GF<MOD>> = vec![];
+    let mut prev: Vec<GF<MOD>> = vec![];
+    // The length of the recurrence.
+    let mut len = 0;
+    // The discrepancy when `prev` was current, and the number of steps since then.
+    let mut prev_delta = GF::one();
+    let mut shift = 1;
+    for i in 0..a.len() {
+        let delta = cur
+            .iter()
+            .zip(a[..i].iter().rev())
+            .fold(a[i], |acc, (c, x)| acc + c * x);
+        if delta == GF::zero() {
+            shift += 1;
+            continue;
+        }
+        // C <- C - (delta / prev_delta) x^shift B, which makes the discrepancy at `i` zero.
+        let coef = delta / prev_delta;
+        let mut next = cur.clone();
+        if next.len() < shift + prev.len() {
+            next.resize(shift + prev.len(), GF::zero());
+        }
+        next[shift - 1] -= coef;
+        for (x, y) in next[shift..].iter_mut().zip(&prev) {
+            *x -= coef * y;
+        }
+        if 2 * len <= i {
+            len = i + 1 - len;
+            prev = std::mem::replace(&mut cur, next);
+            prev_delta = delta;
+            shift = 1;
+        } else {
+            cur = next;
+            shift += 1;
+        }
+    }
+    cur.resize(len, GF::zero());
+    cur.iter().map(|x| -*x).collect()
+}
+
+/// Returns `[x^n] P(x) / Q(x)`, the coefficient of `x^n` in the power series `P(x) / Q(x)`.
+///
+/// Uses the Bostan-Mori algorithm: `P(x) / Q(x) = P(x) Q(-x) / Q(x) Q(-x)`, whose denominator
+/// is even, so only the even or odd part of the numerator is needed to halve `n`.
+///
+/// ## Parameters
+///
+/// - `p`: Coefficients of the numerator
+/// - `q`: Coefficients of the denominator (satisfy `q[0] != 0`)
+/// - `n`: The degree of the wanted coefficient
+///
+/// ## Complexity
+///
+/// `O(d log d log n)` where `d = max(p.len(), q.len())`
+pub fn coeff_of_rational<const MOD: u32>(p: &[GF<MOD>], q: &[GF<MOD>], mut n: u64) -> GF<MOD> {
+    debug_assert!(
+        !q.is_empty() && q[0] != GF::zero(),
+        "The constant term of `q` must not be 0"
+    );
+    let mut p = p.to_vec();
+    let mut q = q.to_vec();
+    while n > 0 {
+        if p.is_empty() {
+            return GF::zero();
+        }
+        let q_neg: Vec<GF<MOD>> = q
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i % 2 == 0 { *x } else { -*x })
+            .collect();
+        let u = ntt_conv(&p, &q_neg);
+        let v = ntt_conv(&q, &q_neg);
+        p = u.into_iter().skip((n % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+    p.first().map_or(GF::zero(), |p0| p0 / q[0])
+}
+
+/// Returns the `n`-th term (0-indexed) of the sequence defined by a linear recurrence.
+///
+/// ## Parameters
+///
+/// - `a`: The first `d` terms `[a_0, a_1, ..., a_(d-1)]`
+/// - `c`: The recurrence `[c_1, c_2, ..., c_d]` as returned by [`berlekamp_massey`]
+///   (satisfy `a.len() >= c.len()`)
+/// - `n`: The index of the wanted term
+///
+/// ## Complexity
+///
+/// `O(d log d log n)` where `d = c.len()`
+pub fn nth_term<const MOD: u32>(a: &[GF<MOD>], c: &[GF<MOD>], n: u64) -> GF<MOD> {
+    debug_assert!(
+        a.len() >= c.len(),
+        "invalid length: {} must not be smaller than {}",
+        a.len(),
+        c.len()
+    );
+    if n < a.len() as u64 {
+        return a[n as usize];
+    }
+    let d = c.len();
+    let mut q = Vec::with_capacity(d + 1);
+    q.push(GF::one());
+    q.extend(c.iter().map(|x| -*x));
+    // P = A Q mod x^d, where A is the generating function of `a`.
+    let mut p = ntt_conv(&a[..d], &q);
+    p.truncate(d);
+    coeff_of_rational(&p, &q, n)
+}
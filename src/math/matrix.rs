@@ -0,0 +1,282 @@
+//! Matrix
+//!
+//! Dense matrices over an arbitrary [`Semiring`].
+//!
+//! The same code computes linear recurrences over `GF<MOD>` (with `R = GF<MOD>`) and shortest
+//! walks with exactly `k` edges (with `R = MinPlus<i64>`).
+//!
+//! # Available operations
+//!
+//! - [`Matrix::zero`], [`Matrix::identity`]: Construct special matrices
+//! - `&a * &b`: Matrix product
+//! - [`Matrix::pow`]: Matrix power by repeated squaring
+//! - [`Matrix::apply`]: Matrix-vector product
+
+use crate::algebra::semiring::Semiring;
+
+use std::ops::{Index, IndexMut, Mul};
+
+/// A dense `h × w` matrix over a semiring `R`, stored in row-major order.
+///
+/// # Type Parameters
+///
+/// - `R`: The semiring of the entries (the entries have type `R::S`)
+pub struct Matrix<R: Semiring> {
+    /// Number of rows
+    h: usize,
+
+    /// Number of columns
+    w: usize,
+
+    /// Entries in row-major order: `data[i * w + j]` is the `(i, j)` entry
+    data: Box<[R::S]>,
+}
+
+impl<R: Semiring> Clone for Matrix<R> {
+    fn clone(&self) -> Self {
+        Self {
+            h: self.h,
+            w: self.w,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<R: Semiring> PartialEq for Matrix<R>
+where
+    R::S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.h == other.h && self.w == other.w && self.data == other.data
+    }
+}
+
+impl<R: Semiring> std::fmt::Debug for Matrix<R>
+where
+    R::S: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.data.chunks(self.w.max(1)))
+            .finish()
+    }
+}
+
+impl<R: Semiring> Matrix<R> {
+    /// Creates an `h × w` matrix with all entries equal to `R::zero()`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw)`
+    pub fn zero(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            data: vec![R::zero(); h * w].into_boxed_slice(),
+        }
+    }
+
+    /// Creates the `n × n` identity matrix.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n^2)`
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::zero(n, n);
+        for i in 0..n {
+            res.data[i * n + i] = R::one();
+        }
+        res
+    }
+
+    /// Creates a matrix from its rows.
+    ///
+    /// ## Parameters
+    ///
+    /// - `rows`: The rows of the matrix (all must have the same length)
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<R::S>>) -> Self {
+        let h = rows.len();
+        let w = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(h * w);
+        for row in rows {
+            assert_eq!(row.len(), w, "all rows must have the same length");
+            data.extend(row);
+        }
+        Self {
+            h,
+            w,
+            data: data.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    /// Returns the `i`-th row as a slice.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= self.height()`.
+    pub fn row(&self, i: usize) -> &[R::S] {
+        debug_assert!(
+            i < self.h,
+            "invalid index: {} must be smaller than {}",
+            i,
+            self.h
+        );
+        &self.data[i * self.w..(i + 1) * self.w]
+    }
+
+    /// Returns the transposed matrix.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw)`
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.h * self.w);
+        for j in 0..self.w {
+            for i in 0..self.h {
+                data.push(self.data[i * self.w + j].clone());
+            }
+        }
+        Self {
+            h: self.w,
+            w: self.h,
+            data: data.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the matrix power `self^k`.
+    ///
+    /// `self^0` is the identity matrix.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n^3 log k)` for an `n × n` matrix
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn pow(&self, mut k: u64) -> Self {
+        assert_eq!(self.h, self.w, "matrix must be square");
+        let mut res = Self::identity(self.h);
+        let mut base = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                res = &res * &base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = &base * &base;
+            }
+        }
+        res
+    }
+
+    /// Returns the matrix-vector product `self * v`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hw)`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `v.len() != self.width()`.
+    pub fn apply(&self, v: &[R::S]) -> Vec<R::S> {
+        assert_eq!(v.len(), self.w, "dimension mismatch");
+        (0..self.h)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(R::zero(), |acc, (a, b)| R::add(&acc, &R::mul(a, b)))
+            })
+            .collect()
+    }
+}
+
+impl<R: Semiring> Index<(usize, usize)> for Matrix<R> {
+    type Output = R::S;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        debug_assert!(
+            i < self.h && j < self.w,
+            "invalid index: ({}, {}) must be smaller than ({}, {})",
+            i,
+            j,
+            self.h,
+            self.w
+        );
+        &self.data[i * self.w + j]
+    }
+}
+
+impl<R: Semiring> IndexMut<(usize, usize)> for Matrix<R> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        debug_assert!(
+            i < self.h && j < self.w,
+            "invalid index: ({}, {}) must be smaller than ({}, {})",
+            i,
+            j,
+            self.h,
+            self.w
+        );
+        &mut self.data[i * self.w + j]
+    }
+}
+
+impl<R: Semiring> Mul for &Matrix<R> {
+    type Output = Matrix<R>;
+
+    /// Returns the matrix product `self * rhs`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(hwk)` for an `h × w` matrix `self` and a `w × k` matrix `rhs`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `self.width() != rhs.height()`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.w, rhs.h,
+            "dimension mismatch: {}x{} * {}x{}",
+            self.h, self.w, rhs.h, rhs.w
+        );
+        let mut res = Matrix::zero(self.h, rhs.w);
+        for i in 0..self.h {
+            let out = &mut res.data[i * rhs.w..(i + 1) * rhs.w];
+            for k in 0..self.w {
+                let a = &self.data[i * self.w + k];
+                let row = &rhs.data[k * rhs.w..(k + 1) * rhs.w];
+                for (c, b) in out.iter_mut().zip(row) {
+                    *c = R::add(c, &R::mul(a, b));
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<R: Semiring> Mul for Matrix<R> {
+    type Output = Matrix<R>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
@@ -1,7 +1,20 @@
+pub mod binom_mod;
+pub mod comb_sequence;
 pub mod conv;
+pub mod dyn_modint;
+pub mod fps;
 pub mod gcd;
 pub mod gf;
+pub mod gf64;
 pub mod gf_comb;
 pub mod integer;
+pub mod lagrange;
+pub mod linear_algebra;
+pub mod linear_algebra_gf2;
+pub mod linear_recurrence;
+pub mod matrix;
 pub mod modint;
+pub mod montgomery;
+pub mod polynomial;
 pub mod prime;
+pub mod xor_basis;
@@ -5,9 +5,15 @@
 //! # Available functions
 //!
 //! - [`pow_mod`]: Calculate exponentiation modular by some value.
+//! - [`sqrt_mod`]: Calculate a square root modulo a prime number.
+//! - [`inv_mod`]: Calculate a modular inverse modulo any number.
+//! - [`crt`]: Solve a system of congruences (Chinese Remainder Theorem).
 
 use std::ops::{Div, Mul, Rem};
 
+use super::gcd::binary_gcd;
+use super::montgomery::Montgomery64;
+
 /// Returns `a^n` modulo `m`.
 ///
 /// ## Parameters
@@ -36,3 +42,123 @@ pub fn pow_mod<T: Copy + Mul<Output = T> + Div<Output = T> + Rem<Output = T> + F
     }
     res
 }
+
+/// Returns `x` such that `x^2 ≡ a (mod p)`, or `None` if `a` is a quadratic non-residue.
+///
+/// Uses the Tonelli-Shanks algorithm.
+///
+/// ## Parameters
+///
+/// - `a`: The value whose square root is computed
+/// - `p`: A prime number (must be prime: not verified)
+///
+/// ## Returns
+///
+/// One of the square roots in `0..p` (the other one is `p - x`).
+///
+/// ## Complexity
+///
+/// `O(log^2 p)`
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if p == 2 || a <= 1 {
+        return Some(a);
+    }
+
+    let mont = Montgomery64::new(p);
+    let one = mont.one();
+    let legendre = |x: u64| mont.pow(x, (p - 1) / 2);
+    let a = mont.to_mont(a);
+    // Euler's criterion: a is a quadratic residue iff a^((p-1)/2) = 1.
+    if legendre(a) != one {
+        return None;
+    }
+
+    // p - 1 = q 2^s with q odd.
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..)
+        .map(|z| mont.to_mont(z))
+        .find(|&z| legendre(z) != one)
+        .unwrap();
+
+    // Invariant: x^2 = a t, t^(2^(m-1)) = 1 and c^(2^(m-1)) = -1.
+    let mut m = s;
+    let mut c = mont.pow(z, q);
+    let mut t = mont.pow(a, q);
+    let mut x = mont.pow(a, q.div_ceil(2));
+    while t != one {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != one {
+            t2 = mont.mul(t2, t2);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = mont.mul(b, b);
+        }
+        m = i;
+        c = mont.mul(b, b);
+        t = mont.mul(t, c);
+        x = mont.mul(x, b);
+    }
+    Some(mont.from_mont(x))
+}
+
+/// Returns `a^(-1)` modulo `m`, or `None` if `gcd(a, m) != 1`.
+///
+/// Uses the extended Euclidean algorithm, so `m` need not be prime.
+///
+/// ## Complexity
+///
+/// `O(log m)`
+pub fn inv_mod(a: u64, m: u64) -> Option<u64> {
+    // Invariant: a ≡ x * a0 and b ≡ y * a0 (mod m).
+    let (mut a, mut b) = ((a % m) as i128, m as i128);
+    let (mut x, mut y) = (1i128, 0i128);
+    while b != 0 {
+        let q = a / b;
+        (a, b) = (b, a - q * b);
+        (x, y) = (y, x - q * y);
+    }
+    (a == 1).then(|| x.rem_euclid(m as i128) as u64)
+}
+
+/// Returns `(r, l)` such that `x ≡ r (mod l)` is equivalent to the system `x ≡ r_i (mod m_i)`,
+/// or `None` if the system has no solution.
+///
+/// The moduli need not be pairwise coprime; `l` is their least common multiple.
+///
+/// ## Parameters
+///
+/// - `congruences`: Pairs `(r_i, m_i)` with `m_i >= 1`
+///   (the least common multiple of all `m_i` must fit in `u64`)
+///
+/// ## Returns
+///
+/// `(r, l)` with `0 <= r < l`. For an empty system, `(0, 1)`.
+///
+/// ## Complexity
+///
+/// `O(k log max m_i)` where `k = congruences.len()`
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut r, mut l) = (0u64, 1u64);
+    for &(ri, mi) in congruences {
+        debug_assert!(mi >= 1, "modulus must be positive");
+        let ri = ri % mi;
+        let g = binary_gcd(l, mi);
+        let diff = ri as i128 - r as i128;
+        if diff % g as i128 != 0 {
+            return None;
+        }
+        // r + l t ≡ ri (mod mi)  <=>  (l/g) t ≡ diff/g (mod mi/g)
+        let m = mi / g;
+        let t = (diff / g as i128).rem_euclid(m as i128) as u128
+            * inv_mod(l / g, m).unwrap() as u128
+            % m as u128;
+        r = (r as u128 + l as u128 * t) as u64;
+        l *= m;
+    }
+    Some((r, l))
+}
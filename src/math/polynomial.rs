@@ -0,0 +1,210 @@
+//! Polynomial operations over `GF<MOD>`
+//!
+//! Division, evaluation and interpolation of polynomials represented by [`Fps<MOD>`](Fps).
+//! Here an [`Fps`] is regarded as the polynomial `sum_i f_i x^i` of degree `< self.len()`.
+//!
+//! # Available operations
+//!
+//! - [`Fps::div_rem`]: Quotient and remainder of polynomial division
+//! - [`Fps::evaluate`]: Value at a single point (Horner's method)
+//! - [`Fps::multipoint_evaluation`]: Values at many points via the subproduct tree
+//! - [`Fps::interpolate`]: The polynomial through given points (Lagrange interpolation)
+
+use super::fps::Fps;
+use super::gf::GF;
+
+/// Below this size, division is done by schoolbook long division.
+const NAIVE_DIVISION_THRESHOLD: usize = 32;
+
+/// Subtrees with at most this many leaves are evaluated point by point.
+const NAIVE_EVALUATION_WIDTH: usize = 64;
+
+impl<const MOD: u32> Fps<MOD> {
+    /// Returns `(q, r)` such that `self = q * rhs + r` and `deg r < deg rhs`.
+    ///
+    /// Both `q` and `r` have no trailing zero coefficients (the zero polynomial is empty).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is the zero polynomial.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n)` where `n = self.len()`
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let mut f = self.clone();
+        let mut g = rhs.clone();
+        trim(&mut f);
+        trim(&mut g);
+        debug_assert!(!g.is_empty(), "The divisor must not be 0");
+        if f.len() < g.len() {
+            return (Self::zero(), f);
+        }
+        let q_len = f.len() - g.len() + 1;
+        if q_len.min(g.len()) <= NAIVE_DIVISION_THRESHOLD {
+            return div_rem_naive(f, &g);
+        }
+
+        // rev(f) = rev(q) rev(g) (mod x^q_len), where rev reverses the coefficients.
+        let f_rev = Self::new(f.iter().rev().take(q_len).copied().collect());
+        let g_rev = Self::new(g.iter().rev().copied().collect());
+        let mut q = &f_rev * &g_rev.inv(q_len);
+        q.truncate(q_len);
+        q.reverse();
+
+        let mut r = f - &q * &g;
+        r.truncate(g.len() - 1);
+        trim(&mut q);
+        trim(&mut r);
+        (q, r)
+    }
+
+    /// Returns `f(x)`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n)` where `n = self.len()`
+    pub fn evaluate(&self, x: GF<MOD>) -> GF<MOD> {
+        self.iter().rev().fold(GF::zero(), |acc, a| acc * x + a)
+    }
+
+    /// Returns `[f(x0), f(x1), ..., f(x(m-1))]` for `points = [x0, x1, ..., x(m-1)]`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log n + m log^2 m)` where `n = self.len()`, `m = points.len()`
+    pub fn multipoint_evaluation(&self, points: &[GF<MOD>]) -> Vec<GF<MOD>> {
+        if points.is_empty() {
+            return vec![];
+        }
+        let tree = SubproductTree::build(points);
+        tree.evaluate(self.div_rem(&tree.nodes[1]).1)
+    }
+
+    /// Returns the polynomial `f` of degree `< n` with `f(xs[i]) = ys[i]` for all `i`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `xs`: Pairwise distinct points
+    /// - `ys`: Values at the points (satisfy `xs.len() == ys.len()`)
+    ///
+    /// ## Complexity
+    ///
+    /// `O(n log^2 n)` where `n = xs.len()`
+    pub fn interpolate(xs: &[GF<MOD>], ys: &[GF<MOD>]) -> Self {
+        debug_assert_eq!(
+            xs.len(),
+            ys.len(),
+            "The number of points and values must be equal"
+        );
+        if xs.is_empty() {
+            return Self::zero();
+        }
+        // f = sum_i ys[i] / P'(xs[i]) * P(x) / (x - xs[i]) where P = prod_i (x - xs[i]).
+        let tree = SubproductTree::build(xs);
+        let p = &tree.nodes[1];
+        let dp = p.derivative();
+        let weights: Vec<GF<MOD>> = tree
+            .evaluate(dp.div_rem(p).1)
+            .into_iter()
+            .zip(ys)
+            .map(|(d, y)| y / d)
+            .collect();
+        tree.combine(&weights)
+    }
+}
+
+/// Removes the trailing zero coefficients.
+fn trim<const MOD: u32>(f: &mut Fps<MOD>) {
+    while f.last() == Some(&GF::zero()) {
+        f.pop();
+    }
+}
+
+/// Schoolbook long division of `f` by `g` (`g` has a non-zero leading coefficient).
+fn div_rem_naive<const MOD: u32>(mut f: Fps<MOD>, g: &Fps<MOD>) -> (Fps<MOD>, Fps<MOD>) {
+    let m = g.len();
+    let lead_inv = g[m - 1].inv();
+    let mut q = vec![GF::zero(); f.len() + 1 - m];
+    for i in (0..q.len()).rev() {
+        let c = f[i + m - 1] * lead_inv;
+        q[i] = c;
+        for (x, y) in f[i..i + m].iter_mut().zip(g.iter()) {
+            *x -= c * y;
+        }
+    }
+    f.truncate(m - 1);
+    let mut q = Fps::new(q);
+    trim(&mut q);
+    trim(&mut f);
+    (q, f)
+}
+
+/// The subproduct tree of points `x0, x1, ..., x(n-1)`.
+///
+/// A perfect binary tree over `size` leaves (`size` is a power of two `>= n`) where leaf `i` holds
+/// `x - xi` (or `1` for padding) and every internal node holds the product of its children.
+struct SubproductTree<const MOD: u32> {
+    /// Number of points.
+    n: usize,
+
+    /// Number of leaves.
+    size: usize,
+
+    /// `nodes[k]` is the product at node `k` (the root is `1`, children of `k` are `2k`, `2k+1`).
+    nodes: Vec<Fps<MOD>>,
+}
+
+impl<const MOD: u32> SubproductTree<MOD> {
+    /// Builds the tree for `points`.
+    fn build(points: &[GF<MOD>]) -> Self {
+        let n = points.len();
+        let size = n.next_power_of_two();
+        let mut nodes = vec![Fps::one(); 2 * size];
+        for (node, x) in nodes[size..].iter_mut().zip(points) {
+            *node = Fps::new(vec![-*x, GF::one()]);
+        }
+        for k in (1..size).rev() {
+            nodes[k] = &nodes[2 * k] * &nodes[2 * k + 1];
+        }
+        Self { n, size, nodes }
+    }
+
+    /// Returns the values of `f` at the points, given `f` reduced modulo the root.
+    ///
+    /// Remainders are pushed down until subtrees have at most [`NAIVE_EVALUATION_WIDTH`] leaves,
+    /// whose points are then evaluated by Horner's method.
+    fn evaluate(&self, f: Fps<MOD>) -> Vec<GF<MOD>> {
+        let width = self.size.min(NAIVE_EVALUATION_WIDTH);
+        let top = self.size / width;
+        let mut rem = vec![Fps::zero(); 2 * top];
+        rem[1] = f;
+        for k in 1..top {
+            let f = std::mem::take(&mut rem[k]);
+            rem[2 * k] = f.div_rem(&self.nodes[2 * k]).1;
+            rem[2 * k + 1] = f.div_rem(&self.nodes[2 * k + 1]).1;
+        }
+        let mut values = Vec::with_capacity(self.n);
+        for (k, f) in rem[top..].iter().enumerate() {
+            let leaves = &self.nodes[self.size + k * width..self.size + (k + 1) * width];
+            // Leaf `i` is `x - xi`, so `xi = -leaf[0]`.
+            values.extend(leaves.iter().map(|leaf| f.evaluate(-leaf[0])));
+        }
+        values.truncate(self.n);
+        values
+    }
+
+    /// Returns `sum_i weights[i] * P(x) / (x - xi)` where `P` is the product of all leaves.
+    fn combine(&self, weights: &[GF<MOD>]) -> Fps<MOD> {
+        let mut sum = vec![Fps::zero(); 2 * self.size];
+        for (s, w) in sum[self.size..].iter_mut().zip(weights) {
+            *s = Fps::new(vec![*w]);
+        }
+        for k in (1..self.size).rev() {
+            sum[k] = &sum[2 * k] * &self.nodes[2 * k + 1] + &sum[2 * k + 1] * &self.nodes[2 * k];
+        }
+        let mut f = std::mem::take(&mut sum[1]);
+        trim(&mut f);
+        f
+    }
+}
@@ -10,6 +10,7 @@
 //! - [`primitive_root`]: Find a primitive root modulo a prime number.
 
 use super::gcd::binary_gcd;
+use super::montgomery::Montgomery64;
 
 /// Returns `true` if `n` is prime number, otherwise `false`.
 /// Uses the deterministic Miller-Rabin primality test.
@@ -38,30 +39,20 @@ pub fn is_prime(n: u64) -> bool {
     let r = (n - 1).trailing_zeros();
     let d = (n - 1) >> r;
 
+    let mont = Montgomery64::new(n);
+    let one = mont.one();
+    let minus_one = mont.sub(0, one);
     let miller_rabin = |a: u64| -> bool {
         if a % n == 0 {
             return true;
         }
-        let n = n as u128;
-        let mut base = a as u128;
-        let mut pow = 1;
-        let mut d = d;
-        while d > 0 {
-            if d & 1 == 1 {
-                pow *= base;
-                pow %= n;
-            }
-            base *= base;
-            base %= n;
-            d >>= 1;
-        }
-        if pow == 1 || pow == n - 1 {
+        let mut pow = mont.pow(mont.to_mont(a), d);
+        if pow == one || pow == minus_one {
             return true;
         }
         for _ in 1..r {
-            pow *= pow;
-            pow %= n;
-            if pow == n - 1 {
+            pow = mont.mul(pow, pow);
+            if pow == minus_one {
                 return true;
             }
         }
@@ -112,7 +103,8 @@ pub fn divisors(n: u64) -> Vec<u64> {
 }
 
 /// Factorize `n` into its prime factors.
-/// Uses Pollard's rho algorithm for finding larger prime factors.
+/// Uses Pollard's rho algorithm (Brent's variant with Montgomery multiplication) for finding
+/// larger prime factors.
 ///
 /// ## Returns
 ///
@@ -156,27 +148,61 @@ pub fn factorize(mut n: u64) -> Vec<u64> {
             i += 1;
             continue;
         }
-        'LOOP: for t in 1.. {
-            let mut x = t as u64;
-            let mut y = ((x as u128 * x as u128 + t) % n as u128) as u64;
-            loop {
-                let g = binary_gcd(y + n - x, n);
-                if g == 0 || g == n {
-                    break;
+        let g = pollard_rho(n);
+        res[i] /= g;
+        res.push(g);
+    }
+    res.sort_unstable();
+    res
+}
+
+/// Returns a non-trivial divisor of an odd composite `n`.
+///
+/// Uses Brent's variant of Pollard's rho: the iteration `x -> x^2 + c` runs in Montgomery form,
+/// and the differences are multiplied together so that `gcd` is called once per block.
+/// Montgomery form does not change `gcd(·, n)` because `R` is coprime to `n`.
+fn pollard_rho(n: u64) -> u64 {
+    /// Number of differences multiplied before each `gcd`.
+    const BLOCK: usize = 128;
+
+    let mont = Montgomery64::new(n);
+    for t in 1.. {
+        let c = mont.to_mont(t);
+        let f = |x: u64| mont.add(mont.mul(x, x), c);
+        let (mut x, mut y, mut ys) = (c, c, c);
+        let (mut g, mut q, mut r) = (1, mont.one(), 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BLOCK.min(r - k) {
+                    y = f(y);
+                    q = mont.mul(q, mont.sub(x, y));
                 }
+                g = binary_gcd(q, n);
+                k += BLOCK;
+            }
+            r <<= 1;
+        }
+        if g == n {
+            // The block overshot: retry its steps one by one.
+            loop {
+                ys = f(ys);
+                g = binary_gcd(mont.sub(x, ys), n);
                 if g != 1 {
-                    res[i] /= g;
-                    res.push(g);
-                    break 'LOOP;
+                    break;
                 }
-                x = ((x as u128 * x as u128 + t) % n as u128) as u64;
-                y = ((y as u128 * y as u128 + t) % n as u128) as u64;
-                y = ((y as u128 * y as u128 + t) % n as u128) as u64;
             }
         }
+        if g != n {
+            return g;
+        }
     }
-    res.sort_unstable();
-    res
+    unreachable!();
 }
 
 /// Returns the smallest primitive root modulo prime `p`.
@@ -211,26 +237,12 @@ pub fn primitive_root(p: u64) -> u64 {
 
     let mut factor = factorize(p - 1);
     factor.dedup();
+    // `p` is an odd prime here.
+    let mont = Montgomery64::new(p);
+    let one = mont.one();
     for g in 2..p {
-        let mut is_primitive = true;
-        for f in &factor {
-            let mut base = g;
-            let mut exp = (p - 1) / f;
-            let mut res = 1;
-            let modulo = p as u128;
-            while exp > 0 {
-                if exp & 1 == 1 {
-                    res = res * base as u128 % modulo;
-                }
-                base = (base as u128 * base as u128 % modulo) as u64;
-                exp >>= 1;
-            }
-            if res == 1 {
-                is_primitive = false;
-                break;
-            }
-        }
-        if is_primitive {
+        let base = mont.to_mont(g);
+        if factor.iter().all(|f| mont.pow(base, (p - 1) / f) != one) {
             return g;
         }
     }
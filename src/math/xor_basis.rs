@@ -0,0 +1,215 @@
+//! Xor Basis
+//!
+//! A basis of a linear subspace of `GF(2)^64`, where vectors are represented by `u64`
+//! and addition is bitwise xor.
+//!
+//! # Available operations
+//!
+//! - [`XorBasis::insert`]: Add a vector to the spanned space
+//! - [`XorBasis::reduce`]: Reduce a vector by the basis (the minimum of `x ^ y` over the space)
+//! - [`XorBasis::max_xor`]: The maximum of `x ^ y` over the space
+//! - [`XorBasis::kth`]: The `k`-th smallest element of the space
+//! - [`XorBasis::intersection`]: A basis of the intersection of two spaces
+
+/// Number of bits in a vector.
+const W: usize = u64::BITS as usize;
+
+/// A *xor basis* (linear basis over `GF(2)`) of `u64` vectors.
+///
+/// The basis is kept in echelon form: `basis[b]` is either `0` or a vector whose highest set bit
+/// is `b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XorBasis {
+    /// `basis[b]` is `0` or the basis vector with the highest set bit `b`.
+    basis: [u64; W],
+
+    /// The number of basis vectors (dimension of the spanned space).
+    rank: usize,
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XorBasis {
+    /// Creates a new basis of the zero space.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn new() -> Self {
+        Self {
+            basis: [0; W],
+            rank: 0,
+        }
+    }
+
+    /// Returns the dimension of the spanned space.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn len(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns `true` if the spanned space is `{0}`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(1)`
+    pub fn is_empty(&self) -> bool {
+        self.rank == 0
+    }
+
+    /// Adds `x` to the spanned space.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if `x` was independent of the basis (the dimension increased), `false` otherwise.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64)`
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        self.basis[(W - 1) - x.leading_zeros() as usize] = x;
+        self.rank += 1;
+        true
+    }
+
+    /// Returns the minimum of `x ^ y` over all `y` in the spanned space.
+    ///
+    /// The result is `0` if and only if `x` is in the spanned space.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64)`
+    pub fn reduce(&self, mut x: u64) -> u64 {
+        for b in (0..W).rev() {
+            if (x >> b) & 1 == 1 {
+                x ^= self.basis[b];
+            }
+        }
+        x
+    }
+
+    /// Returns `true` if `x` is in the spanned space.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64)`
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    /// Returns the maximum of `x ^ y` over all `y` in the spanned space.
+    ///
+    /// `max_xor(0)` is the maximum element of the spanned space.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64)`
+    pub fn max_xor(&self, mut x: u64) -> u64 {
+        for b in (0..W).rev() {
+            if (x >> b) & 1 == 0 {
+                x ^= self.basis[b];
+            }
+        }
+        x
+    }
+
+    /// Returns the `k`-th (0-indexed) smallest element of the spanned space.
+    ///
+    /// The spanned space has `2^len()` elements, and the `0`-th element is `0`.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(y)`: The `k`-th smallest element.
+    /// - `None`: `k >= 2^len()`.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64^2)`
+    pub fn kth(&self, k: u64) -> Option<u64> {
+        if self.rank < W && k >> self.rank != 0 {
+            return None;
+        }
+        // In reduced echelon form, the i-th smallest basis vector decides bit i of `k`.
+        let reduced = self.reduced();
+        Some(
+            reduced
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| (k >> i) & 1 == 1)
+                .fold(0, |acc, (_, &v)| acc ^ v),
+        )
+    }
+
+    /// Returns an iterator over the basis vectors in ascending order of the highest set bit.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64)` in total
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        self.basis.iter().copied().filter(|&v| v != 0)
+    }
+
+    /// Returns a basis of the intersection of the spaces spanned by `self` and `other`.
+    ///
+    /// ## Algorithm
+    ///
+    /// Vectors of `self` are tagged with themselves, and vectors of `other` with `0`. Reducing
+    /// each vector of `other` keeps `value = tag ^ (element of other)`, with `tag` in the space of
+    /// `self`. When the value becomes `0`, the tag lies in both spaces.
+    ///
+    /// ## Complexity
+    ///
+    /// `O(64^2)`
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut value = self.basis;
+        let mut tag = self.basis;
+        let mut res = Self::new();
+        for y in other.iter() {
+            let (mut v, mut t) = (y, 0);
+            for b in (0..W).rev() {
+                if (v >> b) & 1 == 0 {
+                    continue;
+                }
+                if value[b] == 0 {
+                    value[b] = v;
+                    tag[b] = t;
+                    break;
+                }
+                v ^= value[b];
+                t ^= tag[b];
+            }
+            if v == 0 {
+                res.insert(t);
+            }
+        }
+        res
+    }
+
+    /// Returns the basis vectors in reduced echelon form, in ascending order of the highest set
+    /// bit.
+    fn reduced(&self) -> Vec<u64> {
+        let mut basis = self.basis;
+        for b in 0..W {
+            if basis[b] == 0 {
+                continue;
+            }
+            for c in b + 1..W {
+                if (basis[c] >> b) & 1 == 1 {
+                    basis[c] ^= basis[b];
+                }
+            }
+        }
+        basis.into_iter().filter(|&v| v != 0).collect()
+    }
+}
@@ -0,0 +1,29 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::ds::persistent_queue::PersistentQueue;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let q: usize = stdin.next().unwrap().parse().unwrap();
+
+    let mut versions = Vec::with_capacity(q + 1);
+    versions.push(PersistentQueue::new());
+
+    for _ in 0..q {
+        let c: u8 = stdin.next().unwrap().parse().unwrap();
+        let t: i64 = stdin.next().unwrap().parse().unwrap();
+        let queue = &versions[(t + 1) as usize];
+        if c == 0 {
+            let x: u32 = stdin.next().unwrap().parse().unwrap();
+            let next = queue.push(x);
+            versions.push(next);
+        } else {
+            writeln!(stdout, "{}", queue.front().unwrap()).ok();
+            let next = queue.pop().unwrap();
+            versions.push(next);
+        }
+    }
+}
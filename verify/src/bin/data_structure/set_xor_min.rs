@@ -0,0 +1,27 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::ds::binary_trie::BinaryTrie;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let q: usize = stdin.next().unwrap().parse().unwrap();
+
+    let mut binary_trie = BinaryTrie::new(30);
+
+    for _ in 0..q {
+        let t: u8 = stdin.next().unwrap().parse().unwrap();
+        let x: u64 = stdin.next().unwrap().parse().unwrap();
+        if t == 0 {
+            if binary_trie.count(x) == 0 {
+                binary_trie.insert(x);
+            }
+        } else if t == 1 {
+            binary_trie.erase(x);
+        } else {
+            writeln!(stdout, "{}", binary_trie.min_xor(x).unwrap()).ok();
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::ds::static_rmq::StaticRMQ;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let q: usize = stdin.next().unwrap().parse().unwrap();
+
+    let a: Vec<u32> = (0..n)
+        .map(|_| stdin.next().unwrap().parse().unwrap())
+        .collect();
+
+    let rmq = StaticRMQ::new(a);
+
+    for _ in 0..q {
+        let l: usize = stdin.next().unwrap().parse().unwrap();
+        let r: usize = stdin.next().unwrap().parse().unwrap();
+        writeln!(stdout, "{}", rmq.min(l..r)).ok();
+    }
+}
@@ -1,24 +1,11 @@
 use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
 
-use math_optim::{algebra::group::Group, ds::potential_dsu::PotentialDSU};
+use math_optim::{
+    algebra::group_impl::AdditiveGroup, ds::potential_dsu::PotentialDSU, math::gf::GF,
+};
 
 const MOD: u32 = 998_244_353;
 
-enum O {}
-impl Group for O {
-    type G = u32;
-    fn identity() -> Self::G {
-        0
-    }
-    fn op(a: &Self::G, b: &Self::G) -> Self::G {
-        let x = a + b;
-        if x >= MOD { x - MOD } else { x }
-    }
-    fn inv(a: &Self::G) -> Self::G {
-        if *a == 0 { *a } else { MOD - a }
-    }
-}
-
 fn main() {
     let stdin = read_to_string(stdin()).unwrap();
     let mut stdin = stdin.split_whitespace();
@@ -27,7 +14,7 @@ fn main() {
     let n: usize = stdin.next().unwrap().parse().unwrap();
     let q: usize = stdin.next().unwrap().parse().unwrap();
 
-    let mut potential_dsu = PotentialDSU::<O>::new(n);
+    let mut potential_dsu = PotentialDSU::<AdditiveGroup<GF<MOD>>>::new(n);
 
     for _ in 0..q {
         let t: u8 = stdin.next().unwrap().parse().unwrap();
@@ -36,14 +23,11 @@ fn main() {
 
         if t == 0 {
             let x: u32 = stdin.next().unwrap().parse().unwrap();
-            writeln!(stdout, "{}", potential_dsu.union(v, u, &x) as u8).ok();
+            writeln!(stdout, "{}", potential_dsu.union(v, u, &GF::new(x)) as u8).ok();
+        } else if let Some(x) = potential_dsu.potential(v, u) {
+            writeln!(stdout, "{}", x).ok();
         } else {
-            writeln!(
-                stdout,
-                "{}",
-                potential_dsu.potential(v, u).unwrap_or(!0) as i32
-            )
-            .ok();
+            writeln!(stdout, "-1").ok();
         }
     }
 }
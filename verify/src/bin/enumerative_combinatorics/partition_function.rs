@@ -0,0 +1,20 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::comb_sequence::partition;
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+
+    let ans = partition::<MOD>(n + 1);
+    write!(stdout, "{}", ans[0]).ok();
+    for x in &ans[1..] {
+        write!(stdout, " {}", x).ok();
+    }
+    writeln!(stdout).ok();
+}
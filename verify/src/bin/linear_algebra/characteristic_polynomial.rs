@@ -0,0 +1,30 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, matrix::Matrix};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+
+    let a = Matrix::<GF<MOD>>::from_rows(
+        (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+                    .collect()
+            })
+            .collect(),
+    );
+
+    let p = a.char_poly();
+    write!(stdout, "{}", p[0]).ok();
+    for x in &p[1..] {
+        write!(stdout, " {}", x).ok();
+    }
+    writeln!(stdout).ok();
+}
@@ -0,0 +1,30 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::xor_basis::XorBasis;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let t: usize = stdin.next().unwrap().parse().unwrap();
+    for _ in 0..t {
+        let mut read = || {
+            let n: usize = stdin.next().unwrap().parse().unwrap();
+            let mut basis = XorBasis::new();
+            for _ in 0..n {
+                basis.insert(stdin.next().unwrap().parse().unwrap());
+            }
+            basis
+        };
+        let a = read();
+        let b = read();
+
+        let c = a.intersection(&b);
+        write!(stdout, "{}", c.len()).ok();
+        for x in c.iter() {
+            write!(stdout, " {}", x).ok();
+        }
+        writeln!(stdout).ok();
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, matrix::Matrix};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let m: usize = stdin.next().unwrap().parse().unwrap();
+    let k: usize = stdin.next().unwrap().parse().unwrap();
+
+    let mut read = |h: usize, w: usize| {
+        Matrix::<GF<MOD>>::from_rows(
+            (0..h)
+                .map(|_| {
+                    (0..w)
+                        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+                        .collect()
+                })
+                .collect(),
+        )
+    };
+    let a = read(n, m);
+    let b = read(m, k);
+
+    let c = &a * &b;
+    for i in 0..n {
+        let row = c.row(i);
+        write!(stdout, "{}", row[0]).ok();
+        for x in &row[1..] {
+            write!(stdout, " {}", x).ok();
+        }
+        writeln!(stdout).ok();
+    }
+}
@@ -0,0 +1,34 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, matrix::Matrix};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let k: u64 = stdin.next().unwrap().parse().unwrap();
+
+    let a = Matrix::<GF<MOD>>::from_rows(
+        (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+                    .collect()
+            })
+            .collect(),
+    );
+
+    let b = a.pow(k);
+    for i in 0..n {
+        let row = b.row(i);
+        write!(stdout, "{}", row[0]).ok();
+        for x in &row[1..] {
+            write!(stdout, " {}", x).ok();
+        }
+        writeln!(stdout).ok();
+    }
+}
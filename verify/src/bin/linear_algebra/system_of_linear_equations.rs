@@ -0,0 +1,39 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, matrix::Matrix};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let m: usize = stdin.next().unwrap().parse().unwrap();
+
+    let rows: Vec<Vec<GF<MOD>>> = (0..n)
+        .map(|_| {
+            (0..m)
+                .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+                .collect()
+        })
+        .collect();
+    let a = Matrix::<GF<MOD>>::from_rows(rows);
+    let b: Vec<GF<MOD>> = (0..n)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect();
+
+    let Some((x, kernel)) = a.solve(&b) else {
+        writeln!(stdout, "-1").ok();
+        return;
+    };
+    writeln!(stdout, "{}", kernel.len()).ok();
+    for v in std::iter::once(&x).chain(&kernel) {
+        write!(stdout, "{}", v[0]).ok();
+        for y in &v[1..] {
+            write!(stdout, " {}", y).ok();
+        }
+        writeln!(stdout).ok();
+    }
+}
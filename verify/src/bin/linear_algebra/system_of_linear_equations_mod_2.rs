@@ -0,0 +1,30 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::{ds::bitset::Bitset, math::linear_algebra_gf2::solve};
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let m: usize = stdin.next().unwrap().parse().unwrap();
+
+    let a: Vec<Bitset> = (0..n)
+        .map(|_| {
+            let s: Vec<bool> = stdin.next().unwrap().bytes().map(|c| c == b'1').collect();
+            Bitset::from_bools(&s)
+        })
+        .collect();
+    let b: Vec<bool> = stdin.next().unwrap().bytes().map(|c| c == b'1').collect();
+
+    let Some((x, kernel)) = solve(&a, m, &b) else {
+        writeln!(stdout, "-1").ok();
+        return;
+    };
+    writeln!(stdout, "{}", kernel.len()).ok();
+    for v in std::iter::once(&x).chain(&kernel) {
+        let s: String = (0..m).map(|j| if v.get(j) { '1' } else { '0' }).collect();
+        writeln!(stdout, "{}", s).ok();
+    }
+}
@@ -0,0 +1,19 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::binom_mod::BinomMod;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let t: usize = stdin.next().unwrap().parse().unwrap();
+    let m: u32 = stdin.next().unwrap().parse().unwrap();
+
+    let binom = BinomMod::new(m);
+    for _ in 0..t {
+        let n: u64 = stdin.next().unwrap().parse().unwrap();
+        let k: u64 = stdin.next().unwrap().parse().unwrap();
+        writeln!(stdout, "{}", binom.binom(n, k)).ok();
+    }
+}
@@ -0,0 +1,40 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::dyn_modint::DynModInt;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let t: usize = stdin.next().unwrap().parse().unwrap();
+    let m: u32 = stdin.next().unwrap().parse().unwrap();
+    DynModInt::set_modulus(m);
+
+    let queries: Vec<(usize, usize)> = (0..t)
+        .map(|_| {
+            let n = stdin.next().unwrap().parse().unwrap();
+            let k = stdin.next().unwrap().parse().unwrap();
+            (n, k)
+        })
+        .collect();
+    let max = queries.iter().map(|&(n, _)| n).max().unwrap_or(0);
+
+    let mut fact = vec![DynModInt::one(); max + 1];
+    for i in 1..=max {
+        fact[i] = fact[i - 1] * i;
+    }
+    let mut inv_fact = vec![DynModInt::one(); max + 1];
+    inv_fact[max] = fact[max].inv();
+    for i in (1..=max).rev() {
+        inv_fact[i - 1] = inv_fact[i] * i;
+    }
+
+    for (n, k) in queries {
+        if k > n {
+            writeln!(stdout, "0").ok();
+        } else {
+            writeln!(stdout, "{}", fact[n] * inv_fact[k] * inv_fact[n - k]).ok();
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::modint::sqrt_mod;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let t: usize = stdin.next().unwrap().parse().unwrap();
+
+    for _ in 0..t {
+        let y: u64 = stdin.next().unwrap().parse().unwrap();
+        let p: u64 = stdin.next().unwrap().parse().unwrap();
+        match sqrt_mod(y, p) {
+            Some(x) => writeln!(stdout, "{}", x).ok(),
+            None => writeln!(stdout, "-1").ok(),
+        };
+    }
+}
@@ -0,0 +1,35 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{fps::Fps, gf::GF};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+    let m: usize = stdin.next().unwrap().parse().unwrap();
+
+    let f: Fps<MOD> = (0..n)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect::<Vec<_>>()
+        .into();
+    let g: Fps<MOD> = (0..m)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect::<Vec<_>>()
+        .into();
+
+    let (q, r) = f.div_rem(&g);
+    writeln!(stdout, "{} {}", q.len(), r.len()).ok();
+    for poly in [q, r] {
+        for (i, x) in poly.iter().enumerate() {
+            if i > 0 {
+                write!(stdout, " ").ok();
+            }
+            write!(stdout, "{}", x).ok();
+        }
+        writeln!(stdout).ok();
+    }
+}
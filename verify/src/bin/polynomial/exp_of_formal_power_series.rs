@@ -0,0 +1,25 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{fps::Fps, gf::GF};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+
+    let a: Fps<MOD> = (0..n)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect::<Vec<_>>()
+        .into();
+
+    let ans = a.exp(n);
+    write!(stdout, "{}", ans[0]).ok();
+    for x in &ans[1..] {
+        write!(stdout, " {}", x).ok();
+    }
+    writeln!(stdout).ok();
+}
@@ -0,0 +1,27 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, linear_recurrence::berlekamp_massey};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let n: usize = stdin.next().unwrap().parse().unwrap();
+
+    let a: Vec<GF<MOD>> = (0..n)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect();
+
+    let c = berlekamp_massey(&a);
+    writeln!(stdout, "{}", c.len()).ok();
+    for (i, x) in c.iter().enumerate() {
+        if i > 0 {
+            write!(stdout, " ").ok();
+        }
+        write!(stdout, "{}", x).ok();
+    }
+    writeln!(stdout).ok();
+}
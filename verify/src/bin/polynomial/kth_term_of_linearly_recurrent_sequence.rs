@@ -0,0 +1,23 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, linear_recurrence::nth_term};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let d: usize = stdin.next().unwrap().parse().unwrap();
+    let k: u64 = stdin.next().unwrap().parse().unwrap();
+
+    let a: Vec<GF<MOD>> = (0..d)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect();
+    let c: Vec<GF<MOD>> = (0..d)
+        .map(|_| GF::<MOD>::new(stdin.next().unwrap().parse().unwrap()))
+        .collect();
+
+    writeln!(stdout, "{}", nth_term(&a, &c, k)).ok();
+}
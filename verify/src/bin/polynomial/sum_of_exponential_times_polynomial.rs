@@ -0,0 +1,19 @@
+use std::io::{BufWriter, Write, read_to_string, stdin, stdout};
+
+use math_optim::math::{gf::GF, gf_comb::Comb};
+
+const MOD: u32 = 998_244_353;
+
+fn main() {
+    let stdin = read_to_string(stdin()).unwrap();
+    let mut stdin = stdin.split_whitespace();
+    let mut stdout = BufWriter::new(stdout().lock());
+
+    let r: u32 = stdin.next().unwrap().parse().unwrap();
+    let d: u32 = stdin.next().unwrap().parse().unwrap();
+    let n: u64 = stdin.next().unwrap().parse().unwrap();
+
+    let comb = Comb::<MOD>::build(d + 2);
+    let ans = comb.sum_of_exponential_powers(GF::new(r), d, n);
+    writeln!(stdout, "{}", ans).ok();
+}